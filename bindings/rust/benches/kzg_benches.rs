@@ -0,0 +1,573 @@
+use c_kzg::*;
+use criterion::{criterion_group, criterion_main, BenchmarkId, Criterion, Throughput};
+use rand::{rngs::ThreadRng, Rng};
+use std::path::Path;
+
+const TRUSTED_SETUP_FILE: &str = "src/trusted_setup.txt";
+
+/// The precompute value used for the benchmarks which do not vary it, as recommended.
+const DEFAULT_PRECOMPUTE: u64 = 8;
+const MAX_PRECOMPUTE: u64 = 15;
+
+const BLOB_COUNTS: [usize; 7] = [1, 2, 4, 8, 16, 32, 64];
+const MAX_BLOB_COUNT: usize = 64;
+
+/// The subsets of cells given to recovery, as a name and the `modulus` of [`partial_cells`].
+const MISSING_CELLS: [(&str, usize); 4] = [
+    ("50%", 2),
+    ("25%", 4),
+    ("12.5%", 8),
+    ("1", CELLS_PER_EXT_BLOB),
+];
+
+const CELL_PROOF_COUNTS: [usize; 5] = [1, 8, 32, 64, 128];
+
+/// The numbers of field elements given to each [`CommitmentBuilder::update`] call.
+const CHUNK_SIZES: [usize; 4] = [1, 64, 512, FIELD_ELEMENTS_PER_BLOB];
+
+fn generate_random_field_element(rng: &mut ThreadRng) -> Bytes32 {
+    let mut arr = [0u8; BYTES_PER_FIELD_ELEMENT];
+    rng.fill(&mut arr[..]);
+    arr[0] = 0;
+    arr.into()
+}
+
+fn generate_random_blob(rng: &mut ThreadRng) -> Blob {
+    let mut arr = [0u8; BYTES_PER_BLOB];
+    rng.fill(&mut arr[..]);
+    // Ensure that the blob is canonical by ensuring that
+    // each field element contained in the blob is < BLS_MODULUS
+    for i in 0..FIELD_ELEMENTS_PER_BLOB {
+        arr[i * BYTES_PER_FIELD_ELEMENT] = 0;
+    }
+    arr.into()
+}
+
+fn load_kzg_settings(precompute: u64) -> KzgSettings {
+    let trusted_setup_file = Path::new(TRUSTED_SETUP_FILE);
+    assert!(trusted_setup_file.exists());
+    KzgSettings::load_trusted_setup_file(trusted_setup_file, precompute).unwrap()
+}
+
+/// Returns the cells whose index is not a multiple of `modulus`, so one in `modulus` is missing.
+fn partial_cells(cells: &[Cell], modulus: usize) -> (Vec<u64>, Vec<Cell>) {
+    (0..CELLS_PER_EXT_BLOB)
+        .filter(|i| !i.is_multiple_of(modulus))
+        .map(|i| (i as u64, cells[i]))
+        .unzip()
+}
+
+pub fn criterion_benchmark(c: &mut Criterion) {
+    let mut rng = rand::rng();
+    let kzg_settings = load_kzg_settings(DEFAULT_PRECOMPUTE);
+
+    let blobs: Vec<Blob> = (0..MAX_BLOB_COUNT)
+        .map(|_| generate_random_blob(&mut rng))
+        .collect();
+    let commitments: Vec<Bytes48> = blobs
+        .iter()
+        .map(|blob| {
+            kzg_settings
+                .blob_to_kzg_commitment(blob)
+                .unwrap()
+                .to_bytes()
+        })
+        .collect();
+    let proofs: Vec<Bytes48> = blobs
+        .iter()
+        .zip(&commitments)
+        .map(|(blob, commitment)| {
+            kzg_settings
+                .compute_blob_kzg_proof(blob, commitment)
+                .unwrap()
+                .to_bytes()
+        })
+        .collect();
+    let validated_commitments: Vec<ValidatedKzgCommitment> = commitments
+        .iter()
+        .map(|commitment| ValidatedKzgCommitment::new(commitment).unwrap())
+        .collect();
+    let validated_proofs: Vec<ValidatedKzgProof> = proofs
+        .iter()
+        .map(|proof| ValidatedKzgProof::new(proof).unwrap())
+        .collect();
+    let z = generate_random_field_element(&mut rng);
+    let (point_proof, y) = kzg_settings.compute_kzg_proof(&blobs[0], &z).unwrap();
+    let point_proof = point_proof.to_bytes();
+    let (cells, cell_proofs) = kzg_settings
+        .compute_cells_and_kzg_proofs(&blobs[0])
+        .unwrap();
+    let cell_proofs: Vec<Bytes48> = cell_proofs.iter().map(|proof| proof.to_bytes()).collect();
+    let data_columns = kzg_settings.compute_data_columns(&blobs).unwrap();
+
+    /* Trusted setup */
+
+    let trusted_setup = std::fs::read_to_string(TRUSTED_SETUP_FILE).unwrap();
+    c.bench_function("parse_kzg_trusted_setup", |b| {
+        b.iter_with_large_drop(|| KzgSettings::parse_kzg_trusted_setup(&trusted_setup, 0).unwrap())
+    });
+
+    let mut group = c.benchmark_group("load_trusted_setup_file");
+    for precompute in 0..=MAX_PRECOMPUTE {
+        group.bench_with_input(
+            BenchmarkId::from_parameter(precompute),
+            &precompute,
+            |b, &precompute| b.iter_with_large_drop(|| load_kzg_settings(precompute)),
+        );
+    }
+    group.finish();
+
+    /* EIP-4844 */
+
+    c.bench_function("blob_to_kzg_commitment", |b| {
+        b.iter(|| kzg_settings.blob_to_kzg_commitment(&blobs[0]).unwrap())
+    });
+
+    let field_elements: Vec<Bytes32> = blobs[0]
+        .chunks_exact(BYTES_PER_FIELD_ELEMENT)
+        .map(|bytes| Bytes32::from_bytes(bytes).unwrap())
+        .collect();
+    let mut group = c.benchmark_group("commitment_builder");
+    for chunk_size in CHUNK_SIZES {
+        group.bench_with_input(
+            BenchmarkId::new("chunk", chunk_size),
+            &chunk_size,
+            |b, &chunk_size| {
+                b.iter(|| {
+                    let mut builder = CommitmentBuilder::new(&kzg_settings);
+                    for (i, chunk) in field_elements.chunks(chunk_size).enumerate() {
+                        builder.update(i * chunk_size, chunk).unwrap();
+                    }
+                    builder.finalize()
+                })
+            },
+        );
+    }
+    group.finish();
+
+    c.bench_function("compute_kzg_proof", |b| {
+        b.iter(|| kzg_settings.compute_kzg_proof(&blobs[0], &z).unwrap())
+    });
+
+    c.bench_function("compute_blob_kzg_proof", |b| {
+        b.iter(|| {
+            kzg_settings
+                .compute_blob_kzg_proof(&blobs[0], &commitments[0])
+                .unwrap()
+        })
+    });
+
+    c.bench_function("blob_to_kzg_commitment_and_proof", |b| {
+        b.iter(|| {
+            kzg_settings
+                .blob_to_kzg_commitment_and_proof(&blobs[0])
+                .unwrap()
+        })
+    });
+
+    c.bench_function("verify_kzg_proof", |b| {
+        b.iter(|| {
+            kzg_settings
+                .verify_kzg_proof(&commitments[0], &z, &y, &point_proof)
+                .unwrap()
+        })
+    });
+
+    let validated_point_proof = ValidatedKzgProof::new(&point_proof).unwrap();
+    c.bench_function("verify_validated_kzg_proof", |b| {
+        b.iter(|| {
+            kzg_settings
+                .verify_validated_kzg_proof(
+                    &validated_commitments[0],
+                    &z,
+                    &y,
+                    &validated_point_proof,
+                )
+                .unwrap()
+        })
+    });
+
+    c.bench_function("verify_blob_kzg_proof", |b| {
+        b.iter(|| {
+            kzg_settings
+                .verify_blob_kzg_proof(&blobs[0], &commitments[0], &proofs[0])
+                .unwrap()
+        })
+    });
+
+    c.bench_function("verify_validated_blob_kzg_proof", |b| {
+        b.iter(|| {
+            kzg_settings
+                .verify_validated_blob_kzg_proof(
+                    &blobs[0],
+                    &validated_commitments[0],
+                    &validated_proofs[0],
+                )
+                .unwrap()
+        })
+    });
+
+    let mut group = c.benchmark_group("verify_blob_kzg_proof_batch");
+    for count in BLOB_COUNTS {
+        group.throughput(Throughput::Elements(count as u64));
+        group.bench_with_input(BenchmarkId::from_parameter(count), &count, |b, &count| {
+            b.iter(|| {
+                kzg_settings
+                    .verify_blob_kzg_proof_batch(
+                        &blobs[..count],
+                        &commitments[..count],
+                        &proofs[..count],
+                    )
+                    .unwrap()
+            })
+        });
+    }
+    group.finish();
+
+    let mut group = c.benchmark_group("verify_validated_blob_kzg_proof_batch");
+    for count in BLOB_COUNTS {
+        group.throughput(Throughput::Elements(count as u64));
+        group.bench_with_input(BenchmarkId::from_parameter(count), &count, |b, &count| {
+            b.iter(|| {
+                kzg_settings
+                    .verify_validated_blob_kzg_proof_batch(
+                        &blobs[..count],
+                        &validated_commitments[..count],
+                        &validated_proofs[..count],
+                    )
+                    .unwrap()
+            })
+        });
+    }
+    group.finish();
+
+    let sidecars: Vec<BlobTransactionSidecar> = BLOB_COUNTS
+        .iter()
+        .map(|&count| BlobTransactionSidecar {
+            blobs: blobs[..count].to_vec(),
+            commitments: commitments[..count].to_vec(),
+            proofs: proofs[..count].to_vec(),
+        })
+        .collect();
+
+    let mut group = c.benchmark_group("verify_blob_transaction_sidecar");
+    for sidecar in &sidecars {
+        let count = sidecar.blobs.len();
+        group.throughput(Throughput::Elements(count as u64));
+        group.bench_with_input(BenchmarkId::from_parameter(count), sidecar, |b, sidecar| {
+            b.iter(|| {
+                kzg_settings
+                    .verify_blob_transaction_sidecar(sidecar)
+                    .unwrap()
+            })
+        });
+    }
+    group.finish();
+
+    let mut group = c.benchmark_group("convert_blob_transaction_sidecar");
+    for sidecar in &sidecars {
+        let count = sidecar.blobs.len();
+        group.throughput(Throughput::Elements(count as u64));
+        group.bench_with_input(BenchmarkId::from_parameter(count), sidecar, |b, sidecar| {
+            b.iter(|| {
+                kzg_settings
+                    .convert_blob_transaction_sidecar(sidecar)
+                    .unwrap()
+            })
+        });
+    }
+    group.finish();
+
+    let mut group = c.benchmark_group("blobs_bundle_v1_validate");
+    for count in BLOB_COUNTS {
+        let bundle = BlobsBundleV1 {
+            commitments: commitments[..count].to_vec(),
+            proofs: proofs[..count].to_vec(),
+            blobs: blobs[..count].to_vec(),
+        };
+        group.throughput(Throughput::Elements(count as u64));
+        group.bench_with_input(BenchmarkId::from_parameter(count), &bundle, |b, bundle| {
+            b.iter(|| bundle.validate(&kzg_settings).unwrap())
+        });
+    }
+    group.finish();
+
+    let mut group = c.benchmark_group("verify_blob_transaction_sidecar_v1");
+    for sidecar in &sidecars {
+        let count = sidecar.blobs.len();
+        let sidecar = kzg_settings
+            .convert_blob_transaction_sidecar(sidecar)
+            .unwrap();
+        group.throughput(Throughput::Elements(count as u64));
+        group.bench_with_input(
+            BenchmarkId::from_parameter(count),
+            &sidecar,
+            |b, sidecar| {
+                b.iter(|| {
+                    kzg_settings
+                        .verify_blob_transaction_sidecar_v1(sidecar)
+                        .unwrap()
+                })
+            },
+        );
+    }
+    group.finish();
+
+    /* EIP-7594 */
+
+    c.bench_function("compute_cells", |b| {
+        b.iter(|| kzg_settings.compute_cells(&blobs[0]).unwrap())
+    });
+
+    c.bench_function("compute_extended_blob", |b| {
+        b.iter(|| kzg_settings.compute_extended_blob(&blobs[0]).unwrap())
+    });
+
+    c.bench_function("cell_evaluation_point", |b| {
+        b.iter(|| kzg_settings.cell_evaluation_point(1, 1).unwrap())
+    });
+
+    let mut group = c.benchmark_group("compute_cell_kzg_proofs");
+    for count in CELL_PROOF_COUNTS {
+        let cell_indices: Vec<u64> = (0..count as u64).collect();
+        group.throughput(Throughput::Elements(count as u64));
+        group.bench_with_input(
+            BenchmarkId::from_parameter(count),
+            &cell_indices,
+            |b, cell_indices| {
+                b.iter(|| {
+                    kzg_settings
+                        .compute_cell_kzg_proofs(&blobs[0], cell_indices)
+                        .unwrap()
+                })
+            },
+        );
+    }
+    group.finish();
+
+    let mut group = c.benchmark_group("recover_cells");
+    for (name, modulus) in MISSING_CELLS {
+        let (cell_indices, partial) = partial_cells(cells.as_slice(), modulus);
+        group.bench_function(BenchmarkId::new("missing", name), |b| {
+            b.iter(|| kzg_settings.recover_cells(&cell_indices, &partial).unwrap())
+        });
+    }
+    group.finish();
+
+    let mut group = c.benchmark_group("recover_cells_and_kzg_proofs");
+    for (name, modulus) in MISSING_CELLS {
+        let (cell_indices, partial) = partial_cells(cells.as_slice(), modulus);
+        group.bench_function(BenchmarkId::new("missing", name), |b| {
+            b.iter(|| {
+                kzg_settings
+                    .recover_cells_and_kzg_proofs(&cell_indices, &partial)
+                    .unwrap()
+            })
+        });
+    }
+    group.finish();
+
+    let (half_indices, half_cells) = partial_cells(cells.as_slice(), 2);
+    let half_proofs: Vec<Bytes48> = half_indices
+        .iter()
+        .map(|&i| cell_proofs[i as usize])
+        .collect();
+
+    c.bench_function("recover_cells_and_kzg_proofs_checked", |b| {
+        b.iter(|| {
+            kzg_settings
+                .recover_cells_and_kzg_proofs_checked(
+                    &half_indices,
+                    &half_cells,
+                    Some(&commitments[0]),
+                )
+                .unwrap()
+        })
+    });
+
+    c.bench_function("recover_cells_and_kzg_proofs_lenient", |b| {
+        b.iter(|| {
+            kzg_settings
+                .recover_cells_and_kzg_proofs_lenient(&half_indices, &half_cells)
+                .unwrap()
+        })
+    });
+
+    c.bench_function("cells_to_blob", |b| {
+        b.iter(|| {
+            kzg_settings
+                .cells_to_blob(&half_indices, &half_cells)
+                .unwrap()
+        })
+    });
+
+    c.bench_function("verify_and_recover", |b| {
+        b.iter(|| {
+            kzg_settings
+                .verify_and_recover(&commitments[0], &half_indices, &half_cells, &half_proofs)
+                .unwrap()
+        })
+    });
+
+    let mut group = c.benchmark_group("verify_cell_kzg_proof_batch");
+    for count in BLOB_COUNTS {
+        // Every cell of the first `count` blobs.
+        let mut batch_commitments = Vec::new();
+        let mut batch_indices = Vec::new();
+        let mut batch_cells = Vec::new();
+        let mut batch_proofs = Vec::new();
+        for column in &data_columns {
+            batch_commitments.extend_from_slice(&commitments[..count]);
+            batch_indices.extend(std::iter::repeat_n(column.index, count));
+            batch_cells.extend_from_slice(&column.cells[..count]);
+            batch_proofs.extend(column.proofs[..count].iter().map(|proof| proof.to_bytes()));
+        }
+        let validated_batch_commitments: Vec<ValidatedKzgCommitment> = batch_commitments
+            .iter()
+            .map(|commitment| ValidatedKzgCommitment::new(commitment).unwrap())
+            .collect();
+        let validated_batch_proofs: Vec<ValidatedKzgProof> = batch_proofs
+            .iter()
+            .map(|proof| ValidatedKzgProof::new(proof).unwrap())
+            .collect();
+
+        group.throughput(Throughput::Elements(batch_cells.len() as u64));
+        group.bench_function(BenchmarkId::new("rows", count), |b| {
+            b.iter(|| {
+                kzg_settings
+                    .verify_cell_kzg_proof_batch(
+                        &batch_commitments,
+                        &batch_indices,
+                        &batch_cells,
+                        &batch_proofs,
+                    )
+                    .unwrap()
+            })
+        });
+        group.bench_function(BenchmarkId::new("validated_rows", count), |b| {
+            b.iter(|| {
+                kzg_settings
+                    .verify_validated_cell_kzg_proof_batch(
+                        &validated_batch_commitments,
+                        &batch_indices,
+                        &batch_cells,
+                        &validated_batch_proofs,
+                    )
+                    .unwrap()
+            })
+        });
+    }
+    group.finish();
+
+    let mut group = c.benchmark_group("verify_data_column");
+    let column = &data_columns[0];
+    let column_proofs: Vec<Bytes48> = column.proofs.iter().map(|proof| proof.to_bytes()).collect();
+    for count in BLOB_COUNTS {
+        group.throughput(Throughput::Elements(count as u64));
+        group.bench_with_input(BenchmarkId::from_parameter(count), &count, |b, &count| {
+            b.iter(|| {
+                kzg_settings
+                    .verify_data_column(
+                        column.index,
+                        &commitments[..count],
+                        &column.cells[..count],
+                        &column_proofs[..count],
+                    )
+                    .unwrap()
+            })
+        });
+    }
+    group.finish();
+
+    let mut group = c.benchmark_group("compute_data_columns");
+    for count in BLOB_COUNTS {
+        group.throughput(Throughput::Elements(count as u64));
+        group.bench_with_input(BenchmarkId::from_parameter(count), &count, |b, &count| {
+            b.iter(|| kzg_settings.compute_data_columns(&blobs[..count]).unwrap())
+        });
+    }
+    group.finish();
+
+    let mut group = c.benchmark_group("recover_columns");
+    for count in BLOB_COUNTS {
+        // Receive every other column, with the cells of the first `count` blobs.
+        let columns: Vec<DataColumn> = data_columns
+            .iter()
+            .skip(1)
+            .step_by(2)
+            .map(|column| DataColumn {
+                index: column.index,
+                cells: column.cells[..count].to_vec(),
+                proofs: column.proofs[..count].to_vec(),
+                commitments: column.commitments[..count].to_vec(),
+            })
+            .collect();
+        group.throughput(Throughput::Elements(count as u64));
+        group.bench_with_input(
+            BenchmarkId::from_parameter(count),
+            &columns,
+            |b, columns| b.iter(|| kzg_settings.recover_columns(columns).unwrap()),
+        );
+    }
+    group.finish();
+
+    let mut group = c.benchmark_group("blobs_bundle_v2_validate");
+    for count in BLOB_COUNTS {
+        // The cell proofs of each blob are in its row of the data columns.
+        let bundle = BlobsBundleV2 {
+            commitments: commitments[..count].to_vec(),
+            proofs: (0..count)
+                .flat_map(|blob_index| {
+                    data_columns
+                        .iter()
+                        .map(move |column| column.proofs[blob_index].to_bytes())
+                })
+                .collect(),
+            blobs: blobs[..count].to_vec(),
+        };
+        group.throughput(Throughput::Elements(count as u64));
+        group.bench_with_input(BenchmarkId::from_parameter(count), &bundle, |b, bundle| {
+            b.iter(|| bundle.validate(&kzg_settings).unwrap())
+        });
+    }
+    group.finish();
+
+    /* Precompute */
+
+    // Only the cell proofs depend on the precompute value. The settings for each value are loaded
+    // only if one of its benchmarks is selected, as the largest take gigabytes of memory.
+    for precompute in 0..=MAX_PRECOMPUTE {
+        let mut precompute_settings = None;
+
+        c.bench_with_input(
+            BenchmarkId::new("compute_cells_and_kzg_proofs_precompute", precompute),
+            &precompute,
+            |b, &precompute| {
+                let settings =
+                    precompute_settings.get_or_insert_with(|| load_kzg_settings(precompute));
+                b.iter(|| settings.compute_cells_and_kzg_proofs(&blobs[0]).unwrap())
+            },
+        );
+
+        c.bench_with_input(
+            BenchmarkId::new("recover_cells_and_kzg_proofs_precompute", precompute),
+            &precompute,
+            |b, &precompute| {
+                let settings =
+                    precompute_settings.get_or_insert_with(|| load_kzg_settings(precompute));
+                b.iter(|| {
+                    settings
+                        .recover_cells_and_kzg_proofs(&half_indices, &half_cells)
+                        .unwrap()
+                })
+            },
+        );
+    }
+}
+
+criterion_group! {
+    name = benches;
+    config = Criterion::default().sample_size(10);
+    targets = criterion_benchmark
+}
+criterion_main!(benches);
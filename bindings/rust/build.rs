@@ -99,6 +99,11 @@ fn make_bindings(
         .allowlist_file(".*eip4844.h")
         .allowlist_file(".*eip7594.h")
         .allowlist_file(".*setup.h")
+        // Get bindings for the functions that validate commitments and proofs.
+        .allowlist_function("bytes_to_kzg_commitment")
+        .allowlist_function("bytes_to_kzg_proof")
+        // Get bindings for the function that serializes the evaluation challenge.
+        .allowlist_function("bytes_from_bls_field")
         /*
          * Cleanup instructions.
          */
@@ -113,11 +113,22 @@ pub struct Cell {
     bytes: [u8; 2048usize],
 }
 unsafe extern "C" {
+    pub fn bytes_from_bls_field(out: *mut Bytes32, in_: *const fr_t);
+    pub fn bytes_to_kzg_commitment(out: *mut g1_t, b: *const Bytes48) -> C_KZG_RET;
+    pub fn bytes_to_kzg_proof(out: *mut g1_t, b: *const Bytes48) -> C_KZG_RET;
     pub fn blob_to_kzg_commitment(
         out: *mut KZGCommitment,
         blob: *const Blob,
         s: *const KZGSettings,
     ) -> C_KZG_RET;
+    pub fn accumulate_kzg_commitment(
+        commitment: *mut g1_t,
+        field_elements: *const Bytes32,
+        offset: u64,
+        num_field_elements: u64,
+        s: *const KZGSettings,
+    ) -> C_KZG_RET;
+    pub fn finalize_kzg_commitment(out: *mut KZGCommitment, commitment: *const g1_t);
     pub fn compute_kzg_proof(
         proof_out: *mut KZGProof,
         y_out: *mut Bytes32,
@@ -131,6 +142,12 @@ unsafe extern "C" {
         commitment_bytes: *const Bytes48,
         s: *const KZGSettings,
     ) -> C_KZG_RET;
+    pub fn blob_to_kzg_commitment_and_proof(
+        commitment_out: *mut KZGCommitment,
+        proof_out: *mut KZGProof,
+        blob: *const Blob,
+        s: *const KZGSettings,
+    ) -> C_KZG_RET;
     pub fn verify_kzg_proof(
         ok: *mut bool,
         commitment_bytes: *const Bytes48,
@@ -139,6 +156,14 @@ unsafe extern "C" {
         proof_bytes: *const Bytes48,
         s: *const KZGSettings,
     ) -> C_KZG_RET;
+    pub fn verify_kzg_proof_g1(
+        ok: *mut bool,
+        commitment: *const g1_t,
+        z_bytes: *const Bytes32,
+        y_bytes: *const Bytes32,
+        proof: *const g1_t,
+        s: *const KZGSettings,
+    ) -> C_KZG_RET;
     pub fn verify_blob_kzg_proof(
         ok: *mut bool,
         blob: *const Blob,
@@ -146,6 +171,13 @@ unsafe extern "C" {
         proof_bytes: *const Bytes48,
         s: *const KZGSettings,
     ) -> C_KZG_RET;
+    pub fn verify_blob_kzg_proof_g1(
+        ok: *mut bool,
+        blob: *const Blob,
+        commitment: *const g1_t,
+        proof: *const g1_t,
+        s: *const KZGSettings,
+    ) -> C_KZG_RET;
     pub fn verify_blob_kzg_proof_batch(
         ok: *mut bool,
         blobs: *const Blob,
@@ -154,6 +186,14 @@ unsafe extern "C" {
         n: u64,
         s: *const KZGSettings,
     ) -> C_KZG_RET;
+    pub fn verify_blob_kzg_proof_batch_g1(
+        ok: *mut bool,
+        blobs: *const Blob,
+        commitments: *const g1_t,
+        proofs: *const g1_t,
+        n: u64,
+        s: *const KZGSettings,
+    ) -> C_KZG_RET;
     pub fn compute_challenge(
         eval_challenge_out: *mut fr_t,
         blob: *const Blob,
@@ -165,6 +205,13 @@ unsafe extern "C" {
         blob: *const Blob,
         s: *const KZGSettings,
     ) -> C_KZG_RET;
+    pub fn compute_cell_kzg_proofs(
+        proofs: *mut KZGProof,
+        blob: *const Blob,
+        cell_indices: *const u64,
+        num_cells: u64,
+        s: *const KZGSettings,
+    ) -> C_KZG_RET;
     pub fn recover_cells_and_kzg_proofs(
         recovered_cells: *mut Cell,
         recovered_proofs: *mut KZGProof,
@@ -173,6 +220,13 @@ unsafe extern "C" {
         num_cells: u64,
         s: *const KZGSettings,
     ) -> C_KZG_RET;
+    pub fn cells_to_blob(
+        blob: *mut Blob,
+        cell_indices: *const u64,
+        cells: *const Cell,
+        num_cells: u64,
+        s: *const KZGSettings,
+    ) -> C_KZG_RET;
     pub fn verify_cell_kzg_proof_batch(
         ok: *mut bool,
         commitments_bytes: *const Bytes48,
@@ -182,6 +236,17 @@ unsafe extern "C" {
         num_cells: u64,
         s: *const KZGSettings,
     ) -> C_KZG_RET;
+    pub fn verify_cell_kzg_proof_batch_g1(
+        ok: *mut bool,
+        commitments_bytes: *const Bytes48,
+        commitments: *const g1_t,
+        cell_indices: *const u64,
+        cells: *const Cell,
+        proofs_bytes: *const Bytes48,
+        proofs: *const g1_t,
+        num_cells: u64,
+        s: *const KZGSettings,
+    ) -> C_KZG_RET;
     pub fn compute_verify_cell_kzg_proof_batch_challenge(
         challenge_out: *mut fr_t,
         commitments_bytes: *const Bytes48,
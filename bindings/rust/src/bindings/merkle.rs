@@ -0,0 +1,82 @@
+//! SHA-256 Merkle tree helpers shared by the SSZ encoding and the inclusion proofs.
+
+use alloc::vec::Vec;
+
+pub(crate) const BYTES_PER_CHUNK: usize = 32;
+
+pub(crate) type Chunk = [u8; BYTES_PER_CHUNK];
+
+pub(crate) fn hash_concat(left: &Chunk, right: &Chunk) -> Chunk {
+    let mut input = [0u8; 2 * BYTES_PER_CHUNK];
+    input[..BYTES_PER_CHUNK].copy_from_slice(left);
+    input[BYTES_PER_CHUNK..].copy_from_slice(right);
+    let mut out = [0u8; BYTES_PER_CHUNK];
+    unsafe { blst::blst_sha256(out.as_mut_ptr(), input.as_ptr(), input.len()) };
+    out
+}
+
+/// Merkleizes `chunks` as if padded with zero chunks up to the next power of two of `limit`.
+pub(crate) fn merkleize(mut layer: Vec<Chunk>, limit: usize) -> Chunk {
+    debug_assert!(layer.len() <= limit.max(1));
+    let depth = limit.next_power_of_two().trailing_zeros();
+    let mut zero = [0u8; BYTES_PER_CHUNK];
+    for _ in 0..depth {
+        if layer.len() % 2 == 1 {
+            layer.push(zero);
+        }
+        layer = layer
+            .chunks_exact(2)
+            .map(|pair| hash_concat(&pair[0], &pair[1]))
+            .collect();
+        zero = hash_concat(&zero, &zero);
+    }
+    layer.first().copied().unwrap_or(zero)
+}
+
+/// Packs bytes into zero-padded chunks.
+pub(crate) fn pack(bytes: &[u8]) -> Vec<Chunk> {
+    bytes
+        .chunks(BYTES_PER_CHUNK)
+        .map(|bytes| {
+            let mut chunk = [0u8; BYTES_PER_CHUNK];
+            chunk[..bytes.len()].copy_from_slice(bytes);
+            chunk
+        })
+        .collect()
+}
+
+/// Returns the `hash_tree_root` of a fixed-size byte vector.
+pub(crate) fn byte_vector_root(bytes: &[u8]) -> Chunk {
+    let chunks = pack(bytes);
+    let limit = chunks.len();
+    merkleize(chunks, limit)
+}
+
+pub(crate) fn length_chunk(length: usize) -> Chunk {
+    let mut chunk = [0u8; BYTES_PER_CHUNK];
+    chunk[..8].copy_from_slice(&(length as u64).to_le_bytes());
+    chunk
+}
+
+pub(crate) fn mix_in_length(root: &Chunk, length: usize) -> Chunk {
+    hash_concat(root, &length_chunk(length))
+}
+
+/// Checks that `leaf` is at position `index` of a tree of depth `branch.len()` with the given
+/// root, same as `is_valid_merkle_branch` in the consensus specs.
+pub(crate) fn is_valid_merkle_branch<'a>(
+    leaf: &Chunk,
+    branch: impl IntoIterator<Item = &'a Chunk>,
+    index: u64,
+    root: &Chunk,
+) -> bool {
+    let mut value = *leaf;
+    for (i, sibling) in branch.into_iter().enumerate() {
+        value = if (index >> i) & 1 == 1 {
+            hash_concat(sibling, &value)
+        } else {
+            hash_concat(&value, sibling)
+        };
+    }
+    value == *root
+}
@@ -3,10 +3,17 @@
 #![allow(non_snake_case)]
 #![allow(dead_code)]
 
+mod merkle;
+#[cfg(feature = "rlp")]
+pub mod rlp;
 #[cfg(feature = "serde")]
-mod serde;
-#[cfg(test)]
-mod test_formats;
+pub mod serde;
+#[cfg(any(test, feature = "spec-tests"))]
+pub mod spec_tests;
+#[cfg(feature = "ssz")]
+pub mod ssz;
+#[cfg(any(test, feature = "spec-tests"))]
+pub mod test_formats;
 
 include!("./generated.rs");
 
@@ -34,6 +41,22 @@ const NUM_G1_POINTS: usize = 4096;
 /// 65 is fixed and is used for providing multiproofs up to 64 field elements.
 const NUM_G2_POINTS: usize = 65;
 
+/// The maximum number of blob commitments in a block, which also bounds the cells in a column.
+pub const MAX_BLOB_COMMITMENTS_PER_BLOCK: usize = 4096;
+
+/// The depth of the Merkle proof of a blob commitment in the block body.
+pub const KZG_COMMITMENT_INCLUSION_PROOF_DEPTH: usize = 17;
+
+/// The index of the `blob_kzg_commitments` field in the block body.
+const BLOB_KZG_COMMITMENTS_FIELD_INDEX: u64 = 11;
+
+/// The G1 identity/infinity point, same as `G1_IDENTITY` in the C code.
+const G1_IDENTITY: g1_t = g1_t {
+    x: blst_fp { l: [0; 6] },
+    y: blst_fp { l: [0; 6] },
+    z: blst_fp { l: [0; 6] },
+};
+
 /// A trusted (valid) KZG commitment.
 // NOTE: this is a type alias to the struct Bytes48, same as [`KZGProof`] in the C header files. To
 //       facilitate type safety: proofs and commitments should not be interchangeable, we use a
@@ -54,6 +77,25 @@ pub struct KZGProof {
     bytes: [u8; BYTES_PER_PROOF],
 }
 
+/// A KZG commitment which has been checked to be a valid G1 point.
+///
+/// The decompressed point is kept alongside the bytes, so verifying against the same commitment
+/// many times only decompresses it once.
+#[derive(Debug, Copy, Clone, Hash, PartialEq, Eq)]
+pub struct ValidatedKZGCommitment {
+    bytes: Bytes48,
+    point: g1_t,
+}
+
+/// A KZG proof which has been checked to be a valid G1 point.
+///
+/// The decompressed point is kept alongside the bytes, so it is only decompressed once.
+#[derive(Debug, Copy, Clone, Hash, PartialEq, Eq)]
+pub struct ValidatedKZGProof {
+    bytes: Bytes48,
+    point: g1_t,
+}
+
 #[derive(Debug)]
 pub enum Error {
     /// Wrong number of bytes.
@@ -68,6 +110,18 @@ pub enum Error {
     InvalidTrustedSetup(String),
     /// Paired arguments have different lengths.
     MismatchLength(String),
+    /// A chunk of field elements is out of range or overlaps a previously provided chunk.
+    InvalidChunk(String),
+    /// The same cell index was provided more than once with different cells.
+    ConflictingCells(String),
+    /// A cell index is not less than [`CELLS_PER_EXT_BLOB`].
+    InvalidCellIndex(String),
+    /// The cells given for recovery do not come from a single blob, or not from the expected one.
+    InconsistentCells(String),
+    /// The RLP encoding is malformed or not canonical.
+    InvalidRlp(String),
+    /// A field element is not less than the BLS modulus.
+    InvalidFieldElement(String),
     /// Loading the trusted setup failed.
     LoadingTrustedSetupFailed(KzgErrors),
     /// The underlying c-kzg library returned an error.
@@ -85,7 +139,13 @@ impl fmt::Display for Error {
             | Self::InvalidKzgProof(s)
             | Self::InvalidKzgCommitment(s)
             | Self::InvalidTrustedSetup(s)
-            | Self::MismatchLength(s) => f.write_str(s),
+            | Self::MismatchLength(s)
+            | Self::InvalidChunk(s)
+            | Self::ConflictingCells(s)
+            | Self::InvalidCellIndex(s)
+            | Self::InconsistentCells(s)
+            | Self::InvalidRlp(s)
+            | Self::InvalidFieldElement(s) => f.write_str(s),
             Self::LoadingTrustedSetupFailed(s) => write!(f, "KzgErrors: {s:?}"),
             Self::CError(s) => fmt::Debug::fmt(s, f),
         }
@@ -126,6 +186,104 @@ pub fn hex_to_bytes(hex_str: &str) -> Result<Vec<u8>, Error> {
         .map_err(|e| Error::InvalidHexFormat(format!("Failed to decode hex: {e}")))
 }
 
+/// The G1 Lagrange, G2 monomial and G1 monomial points of a trusted setup, in file order.
+type TrustedSetupBytes = (
+    Box<[u8; BYTES_PER_G1_POINT * NUM_G1_POINTS]>,
+    Box<[u8; BYTES_PER_G2_POINT * NUM_G2_POINTS]>,
+    Box<[u8; BYTES_PER_G1_POINT * NUM_G1_POINTS]>,
+);
+
+/// Parses the contents of a KZG trusted setup file into the bytes of its points.
+pub(crate) fn parse_trusted_setup_bytes(trusted_setup: &str) -> Result<TrustedSetupBytes, Error> {
+    let mut lines = trusted_setup.lines();
+
+    // Load number of g1 points
+    let n_g1 = lines
+        .next()
+        .ok_or(KzgErrors::FileFormatError)?
+        .parse::<usize>()
+        .map_err(|_| KzgErrors::ParseError)?;
+    if n_g1 != NUM_G1_POINTS {
+        return Err(KzgErrors::MismatchedNumberOfPoints.into());
+    }
+
+    // Load number of g2 points
+    let n_g2 = lines
+        .next()
+        .ok_or(KzgErrors::FileFormatError)?
+        .parse::<usize>()
+        .map_err(|_| KzgErrors::ParseError)?;
+    if n_g2 != NUM_G2_POINTS {
+        return Err(KzgErrors::MismatchedNumberOfPoints.into());
+    }
+
+    let mut g1_lagrange_bytes = alloc::boxed::Box::new([0; BYTES_PER_G1_POINT * NUM_G1_POINTS]);
+    let mut g2_monomial_bytes = alloc::boxed::Box::new([0; BYTES_PER_G2_POINT * NUM_G2_POINTS]);
+    let mut g1_monomial_bytes = alloc::boxed::Box::new([0; BYTES_PER_G1_POINT * NUM_G1_POINTS]);
+
+    // Load g1 Lagrange bytes
+    g1_lagrange_bytes
+        .chunks_mut(BYTES_PER_G1_POINT)
+        .try_for_each(|chunk| {
+            let line = lines.next().ok_or(KzgErrors::FileFormatError)?;
+            hex::decode_to_slice(line, chunk).map_err(|_| KzgErrors::ParseError)
+        })?;
+
+    // Load g2 monomial bytes
+    g2_monomial_bytes
+        .chunks_mut(BYTES_PER_G2_POINT)
+        .try_for_each(|chunk| {
+            let line = lines.next().ok_or(KzgErrors::FileFormatError)?;
+            hex::decode_to_slice(line, chunk).map_err(|_| KzgErrors::ParseError)
+        })?;
+
+    // Load g1 monomial bytes
+    g1_monomial_bytes
+        .chunks_mut(BYTES_PER_G1_POINT)
+        .try_for_each(|chunk| {
+            let line = lines.next().ok_or(KzgErrors::FileFormatError)?;
+            hex::decode_to_slice(line, chunk).map_err(|_| KzgErrors::ParseError)
+        })?;
+
+    if lines.next().is_some() {
+        return Err(KzgErrors::FileFormatError.into());
+    }
+
+    Ok((g1_lagrange_bytes, g2_monomial_bytes, g1_monomial_bytes))
+}
+
+/// Sorts cells by index and removes duplicates, which must be byte-identical.
+fn sort_and_dedup_cells(
+    cell_indices: &[u64],
+    cells: &[Cell],
+) -> Result<(Vec<u64>, Vec<Cell>), Error> {
+    if cell_indices.len() != cells.len() {
+        return Err(Error::MismatchLength(format!(
+            "There are {} cell indices and {} cells",
+            cell_indices.len(),
+            cells.len()
+        )));
+    }
+    let mut pairs: Vec<(u64, &Cell)> = cell_indices.iter().copied().zip(cells).collect();
+    pairs.sort_unstable_by_key(|(index, _)| *index);
+
+    let mut sorted_indices: Vec<u64> = Vec::with_capacity(pairs.len());
+    let mut sorted_cells: Vec<Cell> = Vec::with_capacity(pairs.len());
+    for (index, cell) in pairs {
+        if sorted_indices.last() == Some(&index) {
+            if sorted_cells.last() != Some(cell) {
+                return Err(Error::ConflictingCells(format!(
+                    "Cell index {index} was provided with different cells"
+                )));
+            }
+            continue;
+        }
+        sorted_indices.push(index);
+        sorted_cells.push(*cell);
+    }
+    Ok((sorted_indices, sorted_cells))
+}
+
 /// Holds the parameters of a kzg trusted setup ceremony.
 impl KZGSettings {
     /// Initializes a trusted setup from a flat array of `FIELD_ELEMENTS_PER_BLOB` G1 points in monomial form, a flat
@@ -189,60 +347,8 @@ impl KZGSettings {
 
     /// Parses the contents of a KZG trusted setup file into a KzgSettings.
     pub fn parse_kzg_trusted_setup(trusted_setup: &str, precompute: u64) -> Result<Self, Error> {
-        let mut lines = trusted_setup.lines();
-
-        // Load number of g1 points
-        let n_g1 = lines
-            .next()
-            .ok_or(KzgErrors::FileFormatError)?
-            .parse::<usize>()
-            .map_err(|_| KzgErrors::ParseError)?;
-        if n_g1 != NUM_G1_POINTS {
-            return Err(KzgErrors::MismatchedNumberOfPoints.into());
-        }
-
-        // Load number of g2 points
-        let n_g2 = lines
-            .next()
-            .ok_or(KzgErrors::FileFormatError)?
-            .parse::<usize>()
-            .map_err(|_| KzgErrors::ParseError)?;
-        if n_g2 != NUM_G2_POINTS {
-            return Err(KzgErrors::MismatchedNumberOfPoints.into());
-        }
-
-        let mut g1_lagrange_bytes = alloc::boxed::Box::new([0; BYTES_PER_G1_POINT * NUM_G1_POINTS]);
-        let mut g2_monomial_bytes = alloc::boxed::Box::new([0; BYTES_PER_G2_POINT * NUM_G2_POINTS]);
-        let mut g1_monomial_bytes = alloc::boxed::Box::new([0; BYTES_PER_G1_POINT * NUM_G1_POINTS]);
-
-        // Load g1 Lagrange bytes
-        g1_lagrange_bytes
-            .chunks_mut(BYTES_PER_G1_POINT)
-            .try_for_each(|chunk| {
-                let line = lines.next().ok_or(KzgErrors::FileFormatError)?;
-                hex::decode_to_slice(line, chunk).map_err(|_| KzgErrors::ParseError)
-            })?;
-
-        // Load g2 monomial bytes
-        g2_monomial_bytes
-            .chunks_mut(BYTES_PER_G2_POINT)
-            .try_for_each(|chunk| {
-                let line = lines.next().ok_or(KzgErrors::FileFormatError)?;
-                hex::decode_to_slice(line, chunk).map_err(|_| KzgErrors::ParseError)
-            })?;
-
-        // Load g1 monomial bytes
-        g1_monomial_bytes
-            .chunks_mut(BYTES_PER_G1_POINT)
-            .try_for_each(|chunk| {
-                let line = lines.next().ok_or(KzgErrors::FileFormatError)?;
-                hex::decode_to_slice(line, chunk).map_err(|_| KzgErrors::ParseError)
-            })?;
-
-        if lines.next().is_some() {
-            return Err(KzgErrors::FileFormatError.into());
-        }
-
+        let (g1_lagrange_bytes, g2_monomial_bytes, g1_monomial_bytes) =
+            parse_trusted_setup_bytes(trusted_setup)?;
         Self::load_trusted_setup(
             g1_monomial_bytes.as_ref(),
             g1_lagrange_bytes.as_ref(),
@@ -354,6 +460,44 @@ impl KZGSettings {
         }
     }
 
+    /// Computes the Fiat-Shamir evaluation challenge of a blob and its commitment, which is the
+    /// point at which [`Self::compute_blob_kzg_proof`] evaluates the blob.
+    pub fn compute_challenge(
+        &self,
+        blob: &Blob,
+        commitment_bytes: &Bytes48,
+    ) -> Result<Bytes32, Error> {
+        let commitment = ValidatedKZGCommitment::new(commitment_bytes)?;
+        let mut challenge = MaybeUninit::<fr_t>::uninit();
+        let mut challenge_bytes = MaybeUninit::<Bytes32>::uninit();
+        unsafe {
+            compute_challenge(challenge.as_mut_ptr(), blob, &commitment.point);
+            bytes_from_bls_field(challenge_bytes.as_mut_ptr(), challenge.as_ptr());
+            Ok(challenge_bytes.assume_init())
+        }
+    }
+
+    pub fn blob_to_kzg_commitment_and_proof(
+        &self,
+        blob: &Blob,
+    ) -> Result<(KZGCommitment, KZGProof), Error> {
+        let mut kzg_commitment = MaybeUninit::<KZGCommitment>::uninit();
+        let mut kzg_proof = MaybeUninit::<KZGProof>::uninit();
+        unsafe {
+            let res = blob_to_kzg_commitment_and_proof(
+                kzg_commitment.as_mut_ptr(),
+                kzg_proof.as_mut_ptr(),
+                blob,
+                self,
+            );
+            if let C_KZG_RET::C_KZG_OK = res {
+                Ok((kzg_commitment.assume_init(), kzg_proof.assume_init()))
+            } else {
+                Err(Error::CError(res))
+            }
+        }
+    }
+
     pub fn verify_kzg_proof(
         &self,
         commitment_bytes: &Bytes48,
@@ -379,6 +523,33 @@ impl KZGSettings {
         }
     }
 
+    /// Same as [`Self::verify_kzg_proof`], but with a commitment and proof which have already
+    /// been validated.
+    pub fn verify_validated_kzg_proof(
+        &self,
+        commitment: &ValidatedKZGCommitment,
+        z_bytes: &Bytes32,
+        y_bytes: &Bytes32,
+        proof: &ValidatedKZGProof,
+    ) -> Result<bool, Error> {
+        let mut verified: MaybeUninit<bool> = MaybeUninit::uninit();
+        unsafe {
+            let res = verify_kzg_proof_g1(
+                verified.as_mut_ptr(),
+                &commitment.point,
+                z_bytes,
+                y_bytes,
+                &proof.point,
+                self,
+            );
+            if let C_KZG_RET::C_KZG_OK = res {
+                Ok(verified.assume_init())
+            } else {
+                Err(Error::CError(res))
+            }
+        }
+    }
+
     pub fn verify_blob_kzg_proof(
         &self,
         blob: &Blob,
@@ -402,6 +573,31 @@ impl KZGSettings {
         }
     }
 
+    /// Same as [`Self::verify_blob_kzg_proof`], but with a commitment and proof which have
+    /// already been validated.
+    pub fn verify_validated_blob_kzg_proof(
+        &self,
+        blob: &Blob,
+        commitment: &ValidatedKZGCommitment,
+        proof: &ValidatedKZGProof,
+    ) -> Result<bool, Error> {
+        let mut verified: MaybeUninit<bool> = MaybeUninit::uninit();
+        unsafe {
+            let res = verify_blob_kzg_proof_g1(
+                verified.as_mut_ptr(),
+                blob,
+                &commitment.point,
+                &proof.point,
+                self,
+            );
+            if let C_KZG_RET::C_KZG_OK = res {
+                Ok(verified.assume_init())
+            } else {
+                Err(Error::CError(res))
+            }
+        }
+    }
+
     pub fn verify_blob_kzg_proof_batch(
         &self,
         blobs: &[Blob],
@@ -440,6 +636,48 @@ impl KZGSettings {
         }
     }
 
+    /// Same as [`Self::verify_blob_kzg_proof_batch`], but with commitments and proofs which have
+    /// already been validated.
+    pub fn verify_validated_blob_kzg_proof_batch(
+        &self,
+        blobs: &[Blob],
+        commitments: &[ValidatedKZGCommitment],
+        proofs: &[ValidatedKZGProof],
+    ) -> Result<bool, Error> {
+        if blobs.len() != commitments.len() {
+            return Err(Error::MismatchLength(format!(
+                "There are {} blobs and {} commitments",
+                blobs.len(),
+                commitments.len()
+            )));
+        }
+        if blobs.len() != proofs.len() {
+            return Err(Error::MismatchLength(format!(
+                "There are {} blobs and {} proofs",
+                blobs.len(),
+                proofs.len()
+            )));
+        }
+        let commitment_points: Vec<g1_t> = commitments.iter().map(|c| c.point).collect();
+        let proof_points: Vec<g1_t> = proofs.iter().map(|p| p.point).collect();
+        let mut verified: MaybeUninit<bool> = MaybeUninit::uninit();
+        unsafe {
+            let res = verify_blob_kzg_proof_batch_g1(
+                verified.as_mut_ptr(),
+                blobs.as_ptr(),
+                commitment_points.as_ptr(),
+                proof_points.as_ptr(),
+                blobs.len() as u64,
+                self,
+            );
+            if let C_KZG_RET::C_KZG_OK = res {
+                Ok(verified.assume_init())
+            } else {
+                Err(Error::CError(res))
+            }
+        }
+    }
+
     pub fn compute_cells(&self, blob: &Blob) -> Result<Box<CellsPerExtBlob>, Error> {
         let mut cells: Box<[Cell; CELLS_PER_EXT_BLOB]> = vec![Cell::default(); CELLS_PER_EXT_BLOB]
             .into_boxed_slice()
@@ -455,6 +693,30 @@ impl KZGSettings {
         }
     }
 
+    /// Extends a blob to [`FIELD_ELEMENTS_PER_EXT_BLOB`] field elements.
+    pub fn compute_extended_blob(&self, blob: &Blob) -> Result<ExtendedBlob, Error> {
+        self.compute_cells(blob).map(ExtendedBlob::new)
+    }
+
+    /// Returns the point at which the field element at `offset` in the cell with index
+    /// `cell_index` evaluates the blob polynomial.
+    ///
+    /// Returns `None` if the cell index or the offset is out of range.
+    pub fn cell_evaluation_point(&self, cell_index: u64, offset: usize) -> Option<Bytes32> {
+        if cell_index >= CELLS_PER_EXT_BLOB as u64 || offset >= FIELD_ELEMENTS_PER_CELL {
+            return None;
+        }
+        let index = cell_index as usize * FIELD_ELEMENTS_PER_CELL + offset;
+        let root = unsafe { *self.brp_roots_of_unity.add(index) };
+        let mut scalar = blst::blst_scalar::default();
+        let mut bytes = [0; BYTES_PER_FIELD_ELEMENT];
+        unsafe {
+            blst::blst_scalar_from_fr(&mut scalar, &blst::blst_fr { l: root.l });
+            blst::blst_bendian_from_scalar(bytes.as_mut_ptr(), &scalar);
+        }
+        Some(Bytes32::new(bytes))
+    }
+
     pub fn compute_cells_and_kzg_proofs(
         &self,
         blob: &Blob,
@@ -479,6 +741,33 @@ impl KZGSettings {
         }
     }
 
+    /// Computes the proofs for the cells at `cell_indices` only, in the same order.
+    ///
+    /// A handful of proofs are computed individually, which is much cheaper than
+    /// [`Self::compute_cells_and_kzg_proofs`]. Larger selections fall back to computing all of
+    /// the proofs.
+    pub fn compute_cell_kzg_proofs(
+        &self,
+        blob: &Blob,
+        cell_indices: &[u64],
+    ) -> Result<Vec<KZGProof>, Error> {
+        let mut proofs = vec![KZGProof::default(); cell_indices.len()];
+        unsafe {
+            let res = compute_cell_kzg_proofs(
+                proofs.as_mut_ptr(),
+                blob,
+                cell_indices.as_ptr(),
+                cell_indices.len() as u64,
+                self,
+            );
+            if let C_KZG_RET::C_KZG_OK = res {
+                Ok(proofs)
+            } else {
+                Err(Error::CError(res))
+            }
+        }
+    }
+
     pub fn recover_cells_and_kzg_proofs(
         &self,
         cell_indices: &[u64],
@@ -518,6 +807,270 @@ impl KZGSettings {
         }
     }
 
+    /// Recovers all of the cells without recomputing the proofs, which is much faster than
+    /// [`Self::recover_cells_and_kzg_proofs`].
+    pub fn recover_cells(
+        &self,
+        cell_indices: &[u64],
+        cells: &[Cell],
+    ) -> Result<Box<CellsPerExtBlob>, Error> {
+        if cell_indices.len() != cells.len() {
+            return Err(Error::MismatchLength(format!(
+                "There are {} cell indices and {} cells",
+                cell_indices.len(),
+                cells.len()
+            )));
+        }
+        let mut recovered_cells: Box<[Cell; CELLS_PER_EXT_BLOB]> =
+            vec![Cell::default(); CELLS_PER_EXT_BLOB]
+                .into_boxed_slice()
+                .try_into()
+                .unwrap();
+        unsafe {
+            let res = recover_cells_and_kzg_proofs(
+                recovered_cells.as_mut_ptr(),
+                ptr::null_mut(),
+                cell_indices.as_ptr(),
+                cells.as_ptr(),
+                cells.len() as u64,
+                self,
+            );
+            if let C_KZG_RET::C_KZG_OK = res {
+                Ok(recovered_cells)
+            } else {
+                Err(Error::CError(res))
+            }
+        }
+    }
+
+    /// Like [`Self::recover_cells_and_kzg_proofs`], but checks that the cells are consistent.
+    ///
+    /// Cells which do not all come from the same blob still recover to some polynomial, which
+    /// then has degree [`FIELD_ELEMENTS_PER_BLOB`] or higher, or recovery ignores some of them.
+    /// This is detected by extending the first half of the recovered cells, which is the blob,
+    /// and comparing the result with every given cell. The proofs are computed from the blob
+    /// along the way. If a commitment is given, the blob must also match it.
+    ///
+    /// [`Error::InconsistentCells`] is returned if either check fails.
+    pub fn recover_cells_and_kzg_proofs_checked(
+        &self,
+        cell_indices: &[u64],
+        cells: &[Cell],
+        commitment_bytes: Option<&Bytes48>,
+    ) -> Result<(Box<CellsPerExtBlob>, Box<ProofsPerExtBlob>), Error> {
+        let recovered_cells = self.recover_cells(cell_indices, cells)?;
+        let mut blob = Box::<Blob>::default();
+        for (chunk, cell) in blob
+            .chunks_exact_mut(BYTES_PER_CELL)
+            .zip(recovered_cells.iter())
+        {
+            chunk.copy_from_slice(&cell.bytes);
+        }
+
+        let (extended_cells, proofs) = self.compute_cells_and_kzg_proofs(&blob)?;
+        let consistent = cell_indices
+            .iter()
+            .zip(cells)
+            .all(|(&index, cell)| extended_cells[index as usize] == *cell);
+        if !consistent {
+            return Err(Error::InconsistentCells(
+                "The cells do not all come from the same blob".into(),
+            ));
+        }
+        if let Some(commitment_bytes) = commitment_bytes {
+            let commitment = self.blob_to_kzg_commitment(&blob)?;
+            if commitment.to_bytes() != *commitment_bytes {
+                return Err(Error::InconsistentCells(
+                    "The recovered blob does not match the commitment".into(),
+                ));
+            }
+        }
+        Ok((extended_cells, proofs))
+    }
+
+    /// Like [`Self::recover_cells_and_kzg_proofs`], but the cells may be given in any order and
+    /// may repeat.
+    ///
+    /// Repeated cell indices must come with identical cells, otherwise
+    /// [`Error::ConflictingCells`] is returned.
+    ///
+    /// The cells are sorted and deduplicated in Rust before calling the C function, which, like
+    /// the other bindings, still requires strictly ascending and unique cell indices.
+    pub fn recover_cells_and_kzg_proofs_lenient(
+        &self,
+        cell_indices: &[u64],
+        cells: &[Cell],
+    ) -> Result<(Box<CellsPerExtBlob>, Box<ProofsPerExtBlob>), Error> {
+        let (cell_indices, cells) = sort_and_dedup_cells(cell_indices, cells)?;
+        self.recover_cells_and_kzg_proofs(&cell_indices, &cells)
+    }
+
+    /// Computes all [`CELLS_PER_EXT_BLOB`] data columns for the blobs of a block.
+    ///
+    /// Each blob is committed to and extended into cells and proofs (in parallel with the
+    /// `parallel` feature), then the results are transposed into columns.
+    pub fn compute_data_columns(&self, blobs: &[Blob]) -> Result<Vec<DataColumn>, Error> {
+        let compute_blob = |blob: &Blob| {
+            let commitment = self.blob_to_kzg_commitment(blob)?;
+            let (cells, proofs) = self.compute_cells_and_kzg_proofs(blob)?;
+            Ok::<_, Error>((commitment, cells, proofs))
+        };
+        #[cfg(feature = "parallel")]
+        let computed_blobs = {
+            use rayon::prelude::*;
+            blobs
+                .par_iter()
+                .map(compute_blob)
+                .collect::<Result<Vec<_>, _>>()?
+        };
+        #[cfg(not(feature = "parallel"))]
+        let computed_blobs = blobs
+            .iter()
+            .map(compute_blob)
+            .collect::<Result<Vec<_>, _>>()?;
+
+        let commitments: Vec<KZGCommitment> = computed_blobs
+            .iter()
+            .map(|(commitment, _, _)| *commitment)
+            .collect();
+        let data_columns = (0..CELLS_PER_EXT_BLOB)
+            .map(|i| DataColumn {
+                index: i as u64,
+                cells: computed_blobs
+                    .iter()
+                    .map(|(_, cells, _)| cells[i])
+                    .collect(),
+                proofs: computed_blobs
+                    .iter()
+                    .map(|(_, _, proofs)| proofs[i])
+                    .collect(),
+                commitments: commitments.clone(),
+            })
+            .collect();
+        Ok(data_columns)
+    }
+
+    /// Recovers every data column of a block given at least half of them.
+    ///
+    /// A data column holds the cell with the same index from every blob in the block. The columns
+    /// are transposed into per-blob inputs, each blob is recovered (in parallel with the
+    /// `parallel` feature) and the results are transposed back into all [`CELLS_PER_EXT_BLOB`]
+    /// columns, ordered by index. Columns may be given in any order and may repeat, as for
+    /// [`Self::recover_cells_and_kzg_proofs_lenient`].
+    ///
+    /// All columns must hold the same commitments, which are copied to the recovered columns. The
+    /// proofs of the given columns are not verified, as every proof is recomputed, so the columns
+    /// should be checked with [`Self::verify_data_column`] first.
+    pub fn recover_columns(&self, columns: &[DataColumn]) -> Result<Vec<DataColumn>, Error> {
+        let commitments = columns
+            .first()
+            .map_or(&[][..], |column| column.commitments.as_slice());
+        let num_blobs = commitments.len();
+        for column in columns {
+            if column.index >= CELLS_PER_EXT_BLOB as u64 {
+                return Err(Error::InvalidCellIndex(format!(
+                    "Column index {} is not less than {CELLS_PER_EXT_BLOB}",
+                    column.index
+                )));
+            }
+            if column.cells.len() != num_blobs || column.proofs.len() != num_blobs {
+                return Err(Error::MismatchLength(format!(
+                    "Column {} has {} cells and {} proofs for {num_blobs} blobs",
+                    column.index,
+                    column.cells.len(),
+                    column.proofs.len()
+                )));
+            }
+            let same_commitments = column.commitments.len() == num_blobs
+                && column
+                    .commitments
+                    .iter()
+                    .zip(commitments)
+                    .all(|(a, b)| a.bytes == b.bytes);
+            if !same_commitments {
+                return Err(Error::InconsistentCells(format!(
+                    "Column {} has different commitments",
+                    column.index
+                )));
+            }
+        }
+        // Check the number of columns here, so that a block without blobs is checked like any
+        // other.
+        let mut unique_indices: Vec<u64> = columns.iter().map(|column| column.index).collect();
+        unique_indices.sort_unstable();
+        unique_indices.dedup();
+        if unique_indices.len() < CELLS_PER_EXT_BLOB / 2 {
+            return Err(Error::InvalidCellIndex(format!(
+                "There are {} distinct columns, fewer than the {} needed for recovery",
+                unique_indices.len(),
+                CELLS_PER_EXT_BLOB / 2
+            )));
+        }
+
+        let column_indices: Vec<u64> = columns.iter().map(|column| column.index).collect();
+        let recover_blob = |blob_index: usize| {
+            let cells: Vec<Cell> = columns
+                .iter()
+                .map(|column| column.cells[blob_index])
+                .collect();
+            self.recover_cells_and_kzg_proofs_lenient(&column_indices, &cells)
+        };
+        #[cfg(feature = "parallel")]
+        let recovered_blobs = {
+            use rayon::prelude::*;
+            (0..num_blobs)
+                .into_par_iter()
+                .map(recover_blob)
+                .collect::<Result<Vec<_>, _>>()?
+        };
+        #[cfg(not(feature = "parallel"))]
+        let recovered_blobs = (0..num_blobs)
+            .map(recover_blob)
+            .collect::<Result<Vec<_>, _>>()?;
+
+        let recovered_columns = (0..CELLS_PER_EXT_BLOB)
+            .map(|i| DataColumn {
+                index: i as u64,
+                cells: recovered_blobs.iter().map(|(cells, _)| cells[i]).collect(),
+                proofs: recovered_blobs
+                    .iter()
+                    .map(|(_, proofs)| proofs[i])
+                    .collect(),
+                commitments: commitments.to_vec(),
+            })
+            .collect();
+        Ok(recovered_columns)
+    }
+
+    /// Returns the original blob given at least half of its cells.
+    ///
+    /// The cells are copied directly when the first half of the extended blob is available,
+    /// otherwise the missing cells are recovered first. Cell indices must be strictly ascending.
+    pub fn cells_to_blob(&self, cell_indices: &[u64], cells: &[Cell]) -> Result<Box<Blob>, Error> {
+        if cell_indices.len() != cells.len() {
+            return Err(Error::MismatchLength(format!(
+                "There are {} cell indices and {} cells",
+                cell_indices.len(),
+                cells.len()
+            )));
+        }
+        let mut blob = Box::<Blob>::default();
+        unsafe {
+            let res = cells_to_blob(
+                &mut *blob,
+                cell_indices.as_ptr(),
+                cells.as_ptr(),
+                cells.len() as u64,
+                self,
+            );
+            if let C_KZG_RET::C_KZG_OK = res {
+                Ok(blob)
+            } else {
+                Err(Error::CError(res))
+            }
+        }
+    }
+
     pub fn verify_cell_kzg_proof_batch(
         &self,
         commitments_bytes: &[Bytes48],
@@ -564,30 +1117,576 @@ impl KZGSettings {
             }
         }
     }
-}
-
-impl Drop for KZGSettings {
-    fn drop(&mut self) {
-        unsafe { free_trusted_setup(self) }
-    }
-}
-
-impl Blob {
-    /// Creates a new blob from a byte array.
-    pub const fn new(bytes: [u8; BYTES_PER_BLOB]) -> Self {
-        Self { bytes }
-    }
 
-    pub fn from_bytes(bytes: &[u8]) -> Result<Self, Error> {
-        if bytes.len() != BYTES_PER_BLOB {
-            return Err(Error::InvalidBytesLength(format!(
-                "Invalid byte length. Expected {} got {}",
-                BYTES_PER_BLOB,
-                bytes.len(),
-            )));
-        }
-        let mut new_bytes = [0; BYTES_PER_BLOB];
-        new_bytes.copy_from_slice(bytes);
+    /// Same as [`Self::verify_cell_kzg_proof_batch`], but with commitments and proofs which have
+    /// already been validated.
+    pub fn verify_validated_cell_kzg_proof_batch(
+        &self,
+        commitments: &[ValidatedKZGCommitment],
+        cell_indices: &[u64],
+        cells: &[Cell],
+        proofs: &[ValidatedKZGProof],
+    ) -> Result<bool, Error> {
+        if cells.len() != commitments.len() {
+            return Err(Error::MismatchLength(format!(
+                "There are {} cells and {} commitments",
+                cells.len(),
+                commitments.len()
+            )));
+        }
+        if cells.len() != cell_indices.len() {
+            return Err(Error::MismatchLength(format!(
+                "There are {} cells and {} column indices",
+                cells.len(),
+                cell_indices.len()
+            )));
+        }
+        if cells.len() != proofs.len() {
+            return Err(Error::MismatchLength(format!(
+                "There are {} cells and {} proofs",
+                cells.len(),
+                proofs.len()
+            )));
+        }
+        // The bytes are still needed for the challenge.
+        let (commitments_bytes, commitment_points): (Vec<Bytes48>, Vec<g1_t>) =
+            commitments.iter().map(|c| (c.bytes, c.point)).unzip();
+        let (proofs_bytes, proof_points): (Vec<Bytes48>, Vec<g1_t>) =
+            proofs.iter().map(|p| (p.bytes, p.point)).unzip();
+        let mut verified: MaybeUninit<bool> = MaybeUninit::uninit();
+        unsafe {
+            let res = verify_cell_kzg_proof_batch_g1(
+                verified.as_mut_ptr(),
+                commitments_bytes.as_ptr(),
+                commitment_points.as_ptr(),
+                cell_indices.as_ptr(),
+                cells.as_ptr(),
+                proofs_bytes.as_ptr(),
+                proof_points.as_ptr(),
+                cells.len() as u64,
+                self,
+            );
+            if let C_KZG_RET::C_KZG_OK = res {
+                Ok(verified.assume_init())
+            } else {
+                Err(Error::CError(res))
+            }
+        }
+    }
+
+    /// Verifies the cells of a data column, one per blob, against the blob commitments.
+    pub fn verify_data_column(
+        &self,
+        column_index: u64,
+        commitments_bytes: &[Bytes48],
+        cells: &[Cell],
+        proofs_bytes: &[Bytes48],
+    ) -> Result<bool, Error> {
+        if column_index >= CELLS_PER_EXT_BLOB as u64 {
+            return Err(Error::InvalidCellIndex(format!(
+                "Column index {column_index} is not less than {CELLS_PER_EXT_BLOB}"
+            )));
+        }
+        let cell_indices = vec![column_index; cells.len()];
+        self.verify_cell_kzg_proof_batch(commitments_bytes, &cell_indices, cells, proofs_bytes)
+    }
+
+    /// Verifies cells of a single blob and recovers all of the cells and proofs from the valid
+    /// ones.
+    ///
+    /// Cells whose proofs do not verify against the commitment, or which are malformed, are
+    /// dropped and their positions in the input are reported in
+    /// [`VerifiedRecovery::rejected`]. If at least half of the cells remain, they are recovered
+    /// as by [`Self::recover_cells_and_kzg_proofs_lenient`], otherwise nothing is recovered. An
+    /// invalid commitment is an error rather than a reason to drop every cell.
+    pub fn verify_and_recover(
+        &self,
+        commitment_bytes: &Bytes48,
+        cell_indices: &[u64],
+        cells: &[Cell],
+        proofs_bytes: &[Bytes48],
+    ) -> Result<VerifiedRecovery, Error> {
+        ValidatedKZGCommitment::new(commitment_bytes)?;
+        if cells.len() != cell_indices.len() {
+            return Err(Error::MismatchLength(format!(
+                "There are {} cells and {} cell indices",
+                cells.len(),
+                cell_indices.len()
+            )));
+        }
+        if cells.len() != proofs_bytes.len() {
+            return Err(Error::MismatchLength(format!(
+                "There are {} cells and {} proofs",
+                cells.len(),
+                proofs_bytes.len()
+            )));
+        }
+
+        let mut rejected = Vec::new();
+        self.find_invalid_cells(
+            commitment_bytes,
+            cell_indices,
+            cells,
+            proofs_bytes,
+            0,
+            &mut rejected,
+        )?;
+
+        let mut valid_indices = Vec::with_capacity(cells.len() - rejected.len());
+        let mut valid_cells = Vec::with_capacity(cells.len() - rejected.len());
+        let mut rejected_iter = rejected.iter().peekable();
+        for (i, (index, cell)) in cell_indices.iter().zip(cells).enumerate() {
+            if rejected_iter.next_if_eq(&&i).is_none() {
+                valid_indices.push(*index);
+                valid_cells.push(*cell);
+            }
+        }
+
+        let mut distinct_indices = valid_indices.clone();
+        distinct_indices.sort_unstable();
+        distinct_indices.dedup();
+        let (cells, proofs) = if distinct_indices.len() >= CELLS_PER_EXT_BLOB / 2 {
+            let (cells, proofs) =
+                self.recover_cells_and_kzg_proofs_lenient(&valid_indices, &valid_cells)?;
+            (Some(cells), Some(proofs))
+        } else {
+            (None, None)
+        };
+        Ok(VerifiedRecovery {
+            rejected,
+            cells,
+            proofs,
+        })
+    }
+
+    /// Finds the cells which do not verify by splitting the batch until each part verifies.
+    ///
+    /// The positions of the invalid cells, plus `offset`, are appended to `rejected` in
+    /// ascending order.
+    fn find_invalid_cells(
+        &self,
+        commitment_bytes: &Bytes48,
+        cell_indices: &[u64],
+        cells: &[Cell],
+        proofs_bytes: &[Bytes48],
+        offset: usize,
+        rejected: &mut Vec<usize>,
+    ) -> Result<(), Error> {
+        if cells.is_empty() {
+            return Ok(());
+        }
+        let commitments = vec![*commitment_bytes; cells.len()];
+        match self.verify_cell_kzg_proof_batch(&commitments, cell_indices, cells, proofs_bytes) {
+            Ok(true) => return Ok(()),
+            Ok(false) | Err(Error::CError(C_KZG_RET::C_KZG_BADARGS)) => {}
+            Err(e) => return Err(e),
+        }
+        if cells.len() == 1 {
+            rejected.push(offset);
+            return Ok(());
+        }
+        let mid = cells.len() / 2;
+        self.find_invalid_cells(
+            commitment_bytes,
+            &cell_indices[..mid],
+            &cells[..mid],
+            &proofs_bytes[..mid],
+            offset,
+            rejected,
+        )?;
+        self.find_invalid_cells(
+            commitment_bytes,
+            &cell_indices[mid..],
+            &cells[mid..],
+            &proofs_bytes[mid..],
+            offset + mid,
+            rejected,
+        )
+    }
+
+    /// Verifies a version 0 blob transaction sidecar, with one proof per blob.
+    pub fn verify_blob_transaction_sidecar(
+        &self,
+        sidecar: &BlobTransactionSidecar,
+    ) -> Result<bool, Error> {
+        self.verify_blob_kzg_proof_batch(&sidecar.blobs, &sidecar.commitments, &sidecar.proofs)
+    }
+
+    /// Verifies a version 1 blob transaction sidecar, with [`CELLS_PER_EXT_BLOB`] cell proofs
+    /// per blob.
+    ///
+    /// The cells of every blob are computed and all of the cell proofs are verified in a single
+    /// batch.
+    pub fn verify_blob_transaction_sidecar_v1(
+        &self,
+        sidecar: &BlobTransactionSidecarV1,
+    ) -> Result<bool, Error> {
+        self.verify_blobs_with_cell_proofs(
+            &sidecar.blobs,
+            &sidecar.commitments,
+            &sidecar.cell_proofs,
+        )
+    }
+
+    /// Verifies blobs against their commitments and [`CELLS_PER_EXT_BLOB`] cell proofs per blob,
+    /// the proofs of the first blob followed by the proofs of the second blob and so on.
+    fn verify_blobs_with_cell_proofs(
+        &self,
+        blobs: &[Blob],
+        commitments_bytes: &[Bytes48],
+        cell_proofs: &[Bytes48],
+    ) -> Result<bool, Error> {
+        if blobs.len() != commitments_bytes.len() {
+            return Err(Error::MismatchLength(format!(
+                "There are {} blobs and {} commitments",
+                blobs.len(),
+                commitments_bytes.len()
+            )));
+        }
+        if blobs.len() * CELLS_PER_EXT_BLOB != cell_proofs.len() {
+            return Err(Error::MismatchLength(format!(
+                "There are {} blobs and {} cell proofs",
+                blobs.len(),
+                cell_proofs.len()
+            )));
+        }
+
+        let mut commitments = Vec::with_capacity(cell_proofs.len());
+        let mut cell_indices = Vec::with_capacity(cell_proofs.len());
+        let mut cells = Vec::with_capacity(cell_proofs.len());
+        for (blob, commitment) in blobs.iter().zip(commitments_bytes) {
+            cells.extend_from_slice(&*self.compute_cells(blob)?);
+            commitments.extend(core::iter::repeat_n(*commitment, CELLS_PER_EXT_BLOB));
+            cell_indices.extend(0..CELLS_PER_EXT_BLOB as u64);
+        }
+        self.verify_cell_kzg_proof_batch(&commitments, &cell_indices, &cells, cell_proofs)
+    }
+
+    /// Converts a version 0 blob transaction sidecar to version 1, replacing the blob proofs
+    /// with cell proofs.
+    ///
+    /// The version 0 sidecar is verified first, [`Error::InvalidKzgProof`] is returned if it
+    /// does not verify.
+    pub fn convert_blob_transaction_sidecar(
+        &self,
+        sidecar: &BlobTransactionSidecar,
+    ) -> Result<BlobTransactionSidecarV1, Error> {
+        if !self.verify_blob_transaction_sidecar(sidecar)? {
+            return Err(Error::InvalidKzgProof(
+                "The blob proofs of the sidecar do not verify".into(),
+            ));
+        }
+        let mut cell_proofs = Vec::with_capacity(sidecar.blobs.len() * CELLS_PER_EXT_BLOB);
+        for blob in &sidecar.blobs {
+            let (_, proofs) = self.compute_cells_and_kzg_proofs(blob)?;
+            cell_proofs.extend(proofs.iter().map(KZGProof::to_bytes));
+        }
+        Ok(BlobTransactionSidecarV1 {
+            blobs: sidecar.blobs.clone(),
+            commitments: sidecar.commitments.clone(),
+            cell_proofs,
+        })
+    }
+}
+
+/// A blob extended to [`FIELD_ELEMENTS_PER_EXT_BLOB`] field elements in evaluation form.
+///
+/// The field elements are in bit-reversed order, the same order as the cells they make up. The
+/// first half of the extended blob is the original blob.
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(::serde::Serialize, ::serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(transparent))]
+pub struct ExtendedBlob {
+    #[cfg_attr(
+        feature = "serde",
+        serde(with = "crate::bindings::serde::ext_blob_array")
+    )]
+    cells: Box<CellsPerExtBlob>,
+}
+
+impl ExtendedBlob {
+    /// Creates a new extended blob from its cells.
+    pub fn new(cells: Box<CellsPerExtBlob>) -> Self {
+        Self { cells }
+    }
+
+    /// Returns the cells of the extended blob.
+    pub fn cells(&self) -> &CellsPerExtBlob {
+        &self.cells
+    }
+
+    /// Consumes the extended blob and returns its cells.
+    pub fn into_cells(self) -> Box<CellsPerExtBlob> {
+        self.cells
+    }
+
+    /// Returns an iterator over the [`FIELD_ELEMENTS_PER_EXT_BLOB`] field elements of the
+    /// extended blob.
+    pub fn field_elements(&self) -> impl Iterator<Item = Bytes32> + '_ {
+        self.cells.iter().flat_map(Cell::field_elements)
+    }
+}
+
+/// The cells and proofs with the same index from every blob in a block, along with the blob
+/// commitments.
+///
+/// The `cells`, `proofs` and `commitments` vectors have one entry per blob, in block order.
+#[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(::serde::Serialize, ::serde::Deserialize))]
+pub struct DataColumn {
+    /// The index of the column, which is the index of each of its cells.
+    pub index: u64,
+    /// The cells of the column, one per blob.
+    pub cells: Vec<Cell>,
+    /// The proofs for the cells of the column, one per blob.
+    pub proofs: Vec<KZGProof>,
+    /// The commitments to the blobs.
+    pub commitments: Vec<KZGCommitment>,
+}
+
+/// The result of [`KZGSettings::verify_and_recover`].
+#[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(::serde::Serialize, ::serde::Deserialize))]
+pub struct VerifiedRecovery {
+    /// The positions in the input of the cells which were dropped, in ascending order.
+    pub rejected: Vec<usize>,
+    /// All of the cells, if enough valid cells remained to recover them.
+    #[cfg_attr(
+        feature = "serde",
+        serde(with = "crate::bindings::serde::option_ext_blob_array")
+    )]
+    pub cells: Option<Box<CellsPerExtBlob>>,
+    /// All of the proofs, if enough valid cells remained to recover them.
+    #[cfg_attr(
+        feature = "serde",
+        serde(with = "crate::bindings::serde::option_ext_blob_array")
+    )]
+    pub proofs: Option<Box<ProofsPerExtBlob>>,
+}
+
+/// The blobs of a blob transaction with their commitments and one proof per blob, as gossiped
+/// before EIP-7594.
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(::serde::Serialize, ::serde::Deserialize))]
+pub struct BlobTransactionSidecar {
+    /// The blobs of the transaction.
+    pub blobs: Vec<Blob>,
+    /// The commitments to the blobs.
+    pub commitments: Vec<Bytes48>,
+    /// The proofs for the blobs, one per blob.
+    pub proofs: Vec<Bytes48>,
+}
+
+/// The blobs of a blob transaction with their commitments and [`CELLS_PER_EXT_BLOB`] cell proofs
+/// per blob, as gossiped after EIP-7594.
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(::serde::Serialize, ::serde::Deserialize))]
+pub struct BlobTransactionSidecarV1 {
+    /// The blobs of the transaction.
+    pub blobs: Vec<Blob>,
+    /// The commitments to the blobs.
+    pub commitments: Vec<Bytes48>,
+    /// The cell proofs for the blobs, the proofs of the first blob followed by the proofs of the
+    /// second blob and so on.
+    pub cell_proofs: Vec<Bytes48>,
+}
+
+/// The blobs of an execution payload with their commitments and one proof per blob, as returned
+/// by `engine_getPayloadV3` and `engine_getPayloadV4`.
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(::serde::Serialize, ::serde::Deserialize))]
+pub struct BlobsBundleV1 {
+    /// The commitments to the blobs.
+    pub commitments: Vec<Bytes48>,
+    /// The proofs for the blobs, one per blob.
+    pub proofs: Vec<Bytes48>,
+    /// The blobs of the payload.
+    pub blobs: Vec<Blob>,
+}
+
+impl BlobsBundleV1 {
+    /// Checks that there is a commitment and proof for every blob and that the proofs verify.
+    pub fn validate(&self, settings: &KZGSettings) -> Result<(), Error> {
+        if !settings.verify_blob_kzg_proof_batch(&self.blobs, &self.commitments, &self.proofs)? {
+            return Err(Error::InvalidKzgProof(
+                "The blob proofs of the bundle do not verify".into(),
+            ));
+        }
+        Ok(())
+    }
+}
+
+/// The blobs of an execution payload with their commitments and [`CELLS_PER_EXT_BLOB`] cell
+/// proofs per blob, as returned by `engine_getPayloadV5`.
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(::serde::Serialize, ::serde::Deserialize))]
+pub struct BlobsBundleV2 {
+    /// The commitments to the blobs.
+    pub commitments: Vec<Bytes48>,
+    /// The cell proofs for the blobs, the proofs of the first blob followed by the proofs of the
+    /// second blob and so on.
+    pub proofs: Vec<Bytes48>,
+    /// The blobs of the payload.
+    pub blobs: Vec<Blob>,
+}
+
+impl BlobsBundleV2 {
+    /// Checks that there is a commitment and [`CELLS_PER_EXT_BLOB`] cell proofs for every blob
+    /// and that the proofs verify.
+    pub fn validate(&self, settings: &KZGSettings) -> Result<(), Error> {
+        if !settings.verify_blobs_with_cell_proofs(&self.blobs, &self.commitments, &self.proofs)? {
+            return Err(Error::InvalidKzgProof(
+                "The cell proofs of the bundle do not verify".into(),
+            ));
+        }
+        Ok(())
+    }
+}
+
+/// A partial view of a blob sidecar, as gossiped by consensus clients before EIP-7594.
+///
+/// The signed block header of the spec container is not included, so the inclusion proof is
+/// checked against the body root from the header instead. For the same reason this type has no
+/// serde or SSZ encoding; it is built from a decoded sidecar rather than decoded itself.
+#[derive(Debug, Clone)]
+pub struct BlobSidecar {
+    /// The index of the blob in the block.
+    pub index: u64,
+    /// The blob.
+    pub blob: Blob,
+    /// The commitment to the blob.
+    pub kzg_commitment: KZGCommitment,
+    /// The proof for the blob.
+    pub kzg_proof: KZGProof,
+    /// The Merkle proof of the commitment in the `blob_kzg_commitments` of the block body.
+    pub kzg_commitment_inclusion_proof: [Bytes32; KZG_COMMITMENT_INCLUSION_PROOF_DEPTH],
+}
+
+impl BlobSidecar {
+    /// Verifies the blob against its commitment and proof.
+    pub fn verify_kzg(&self, settings: &KZGSettings) -> Result<bool, Error> {
+        settings.verify_blob_kzg_proof(
+            &self.blob,
+            &self.kzg_commitment.to_bytes(),
+            &self.kzg_proof.to_bytes(),
+        )
+    }
+
+    /// Verifies that the commitment is at position `index` of the `blob_kzg_commitments` of the
+    /// block body with the given root.
+    pub fn verify_inclusion_proof(&self, body_root: &Bytes32) -> bool {
+        if self.index >= MAX_BLOB_COMMITMENTS_PER_BLOCK as u64 {
+            return false;
+        }
+        // The commitments list is mixed in with its length, hence the extra level.
+        let list_depth = MAX_BLOB_COMMITMENTS_PER_BLOCK.trailing_zeros() + 1;
+        let subtree_index = (BLOB_KZG_COMMITMENTS_FIELD_INDEX << list_depth) | self.index;
+        merkle::is_valid_merkle_branch(
+            &merkle::byte_vector_root(&self.kzg_commitment.bytes),
+            self.kzg_commitment_inclusion_proof
+                .iter()
+                .map(|node| &node.bytes),
+            subtree_index,
+            &body_root.bytes,
+        )
+    }
+}
+
+/// Computes a KZG commitment from chunks of a blob's field elements.
+///
+/// Chunks may be provided in any order. Field elements which are never provided are treated as
+/// zero, so [`finalize`](Self::finalize) returns the same commitment as
+/// [`blob_to_kzg_commitment`](KZGSettings::blob_to_kzg_commitment) does for the equivalent blob.
+#[derive(Debug, Clone)]
+pub struct CommitmentBuilder<'a> {
+    settings: &'a KZGSettings,
+    commitment: g1_t,
+    /// One bit per field element of the blob, set once that field element has been provided.
+    provided: [u64; FIELD_ELEMENTS_PER_BLOB / 64],
+}
+
+impl<'a> CommitmentBuilder<'a> {
+    /// Creates a builder for a commitment to a blob whose field elements are all zero.
+    pub fn new(settings: &'a KZGSettings) -> Self {
+        Self {
+            settings,
+            commitment: G1_IDENTITY,
+            provided: [0; FIELD_ELEMENTS_PER_BLOB / 64],
+        }
+    }
+
+    /// Adds a chunk of field elements, the first of which is at index `offset` in the blob.
+    ///
+    /// Each field element is checked to be canonical. On error, the builder is left unchanged.
+    pub fn update(&mut self, offset: usize, field_elements: &[Bytes32]) -> Result<(), Error> {
+        let end = offset
+            .checked_add(field_elements.len())
+            .filter(|&end| end <= FIELD_ELEMENTS_PER_BLOB)
+            .ok_or_else(|| {
+                Error::InvalidChunk(format!(
+                    "Chunk of {} field elements at offset {offset} does not fit in a blob",
+                    field_elements.len()
+                ))
+            })?;
+        if let Some(index) = (offset..end).find(|&i| self.is_provided(i)) {
+            return Err(Error::InvalidChunk(format!(
+                "Field element {index} has already been provided"
+            )));
+        }
+        unsafe {
+            let res = accumulate_kzg_commitment(
+                &mut self.commitment,
+                field_elements.as_ptr(),
+                offset as u64,
+                field_elements.len() as u64,
+                self.settings,
+            );
+            if let C_KZG_RET::C_KZG_OK = res {
+                (offset..end).for_each(|i| self.provided[i / 64] |= 1 << (i % 64));
+                Ok(())
+            } else {
+                Err(Error::CError(res))
+            }
+        }
+    }
+
+    /// Returns true if the field element at `index` has been provided.
+    pub fn is_provided(&self, index: usize) -> bool {
+        index < FIELD_ELEMENTS_PER_BLOB && self.provided[index / 64] & (1 << (index % 64)) != 0
+    }
+
+    /// Returns the commitment to the provided field elements.
+    pub fn finalize(self) -> KZGCommitment {
+        let mut kzg_commitment = MaybeUninit::<KZGCommitment>::uninit();
+        unsafe {
+            finalize_kzg_commitment(kzg_commitment.as_mut_ptr(), &self.commitment);
+            kzg_commitment.assume_init()
+        }
+    }
+}
+
+impl Drop for KZGSettings {
+    fn drop(&mut self) {
+        unsafe { free_trusted_setup(self) }
+    }
+}
+
+impl Blob {
+    /// Creates a new blob from a byte array.
+    pub const fn new(bytes: [u8; BYTES_PER_BLOB]) -> Self {
+        Self { bytes }
+    }
+
+    pub fn from_bytes(bytes: &[u8]) -> Result<Self, Error> {
+        if bytes.len() != BYTES_PER_BLOB {
+            return Err(Error::InvalidBytesLength(format!(
+                "Invalid byte length. Expected {} got {}",
+                BYTES_PER_BLOB,
+                bytes.len(),
+            )));
+        }
+        let mut new_bytes = [0; BYTES_PER_BLOB];
+        new_bytes.copy_from_slice(bytes);
         Ok(Self::new(new_bytes))
     }
 
@@ -704,6 +1803,66 @@ impl KZGCommitment {
     }
 }
 
+impl ValidatedKZGCommitment {
+    /// Decompresses the commitment and checks that it is a valid G1 point.
+    pub fn new(bytes: &Bytes48) -> Result<Self, Error> {
+        let mut point = MaybeUninit::<g1_t>::uninit();
+        unsafe {
+            let res = bytes_to_kzg_commitment(point.as_mut_ptr(), bytes);
+            if let C_KZG_RET::C_KZG_OK = res {
+                Ok(Self {
+                    bytes: *bytes,
+                    point: point.assume_init(),
+                })
+            } else {
+                Err(Error::CError(res))
+            }
+        }
+    }
+
+    pub fn from_bytes(bytes: &[u8]) -> Result<Self, Error> {
+        Self::new(&KZGCommitment::from_bytes(bytes)?.to_bytes())
+    }
+
+    pub fn to_bytes(&self) -> Bytes48 {
+        self.bytes
+    }
+
+    pub fn as_hex_string(&self) -> String {
+        hex::encode(self.bytes.bytes)
+    }
+}
+
+impl ValidatedKZGProof {
+    /// Decompresses the proof and checks that it is a valid G1 point.
+    pub fn new(bytes: &Bytes48) -> Result<Self, Error> {
+        let mut point = MaybeUninit::<g1_t>::uninit();
+        unsafe {
+            let res = bytes_to_kzg_proof(point.as_mut_ptr(), bytes);
+            if let C_KZG_RET::C_KZG_OK = res {
+                Ok(Self {
+                    bytes: *bytes,
+                    point: point.assume_init(),
+                })
+            } else {
+                Err(Error::CError(res))
+            }
+        }
+    }
+
+    pub fn from_bytes(bytes: &[u8]) -> Result<Self, Error> {
+        Self::new(&KZGProof::from_bytes(bytes)?.to_bytes())
+    }
+
+    pub fn to_bytes(&self) -> Bytes48 {
+        self.bytes
+    }
+
+    pub fn as_hex_string(&self) -> String {
+        hex::encode(self.bytes.bytes)
+    }
+}
+
 impl Cell {
     pub const fn new(bytes: [u8; BYTES_PER_CELL]) -> Self {
         Self { bytes }
@@ -726,6 +1885,13 @@ impl Cell {
         self.bytes
     }
 
+    /// Returns an iterator over the [`FIELD_ELEMENTS_PER_CELL`] field elements of the cell.
+    pub fn field_elements(&self) -> impl Iterator<Item = Bytes32> + '_ {
+        self.bytes
+            .chunks_exact(BYTES_PER_FIELD_ELEMENT)
+            .map(|chunk| Bytes32::new(chunk.try_into().unwrap()))
+    }
+
     pub fn from_hex(hex_str: &str) -> Result<Self, Error> {
         Self::from_bytes(&hex_to_bytes(hex_str)?)
     }
@@ -743,13 +1909,45 @@ impl From<[u8; BYTES_PER_PROOF]> for KZGProof {
     }
 }
 
-impl From<[u8; BYTES_PER_BLOB]> for Blob {
-    fn from(value: [u8; BYTES_PER_BLOB]) -> Self {
-        Self { bytes: value }
+impl TryFrom<KZGCommitment> for ValidatedKZGCommitment {
+    type Error = Error;
+
+    fn try_from(value: KZGCommitment) -> Result<Self, Self::Error> {
+        Self::new(&value.to_bytes())
     }
 }
 
-impl From<[u8; 32]> for Bytes32 {
+impl TryFrom<KZGProof> for ValidatedKZGProof {
+    type Error = Error;
+
+    fn try_from(value: KZGProof) -> Result<Self, Self::Error> {
+        Self::new(&value.to_bytes())
+    }
+}
+
+impl From<ValidatedKZGCommitment> for KZGCommitment {
+    fn from(value: ValidatedKZGCommitment) -> Self {
+        Self {
+            bytes: value.bytes.bytes,
+        }
+    }
+}
+
+impl From<ValidatedKZGProof> for KZGProof {
+    fn from(value: ValidatedKZGProof) -> Self {
+        Self {
+            bytes: value.bytes.bytes,
+        }
+    }
+}
+
+impl From<[u8; BYTES_PER_BLOB]> for Blob {
+    fn from(value: [u8; BYTES_PER_BLOB]) -> Self {
+        Self { bytes: value }
+    }
+}
+
+impl From<[u8; 32]> for Bytes32 {
     fn from(value: [u8; 32]) -> Self {
         Self { bytes: value }
     }
@@ -914,9 +2112,10 @@ unsafe impl Send for KZGSettings {}
 
 #[cfg(test)]
 #[allow(unused_imports, dead_code)]
-mod tests {
+pub(crate) mod tests {
     use super::*;
     use rand::{rngs::ThreadRng, Rng};
+    use spec_tests::SpecTestHandler;
     use std::{fs, path::PathBuf};
     use test_formats::{
         blob_to_kzg_commitment_test, compute_blob_kzg_proof, compute_cells,
@@ -925,7 +2124,7 @@ mod tests {
         verify_kzg_proof,
     };
 
-    fn generate_random_blob(rng: &mut ThreadRng) -> Blob {
+    pub(crate) fn generate_random_blob(rng: &mut ThreadRng) -> Blob {
         let mut arr = [0u8; BYTES_PER_BLOB];
         rng.fill(&mut arr[..]);
         // Ensure that the blob is canonical by ensuring that
@@ -988,506 +2187,1146 @@ mod tests {
         test_simple(trusted_setup_file);
     }
 
-    const BLOB_TO_KZG_COMMITMENT_TESTS: &str = "tests/blob_to_kzg_commitment/*/*/*";
-    const COMPUTE_KZG_PROOF_TESTS: &str = "tests/compute_kzg_proof/*/*/*";
-    const COMPUTE_BLOB_KZG_PROOF_TESTS: &str = "tests/compute_blob_kzg_proof/*/*/*";
-    const VERIFY_KZG_PROOF_TESTS: &str = "tests/verify_kzg_proof/*/*/*";
-    const VERIFY_BLOB_KZG_PROOF_TESTS: &str = "tests/verify_blob_kzg_proof/*/*/*";
-    const VERIFY_BLOB_KZG_PROOF_BATCH_TESTS: &str = "tests/verify_blob_kzg_proof_batch/*/*/*";
+    #[test]
+    fn test_commitment_builder() {
+        let mut rng = rand::rng();
+        let trusted_setup_file = Path::new("src/trusted_setup.txt");
+        assert!(trusted_setup_file.exists());
+        let kzg_settings = KZGSettings::load_trusted_setup_file(trusted_setup_file, 0).unwrap();
+
+        let blob = generate_random_blob(&mut rng);
+        let expected = kzg_settings.blob_to_kzg_commitment(&blob).unwrap();
+        let field_elements: Vec<Bytes32> = blob
+            .chunks(BYTES_PER_FIELD_ELEMENT)
+            .map(|chunk| Bytes32::from_bytes(chunk).unwrap())
+            .collect();
+
+        // Split the blob at random points and provide the chunks in reverse order.
+        let mut bounds: Vec<usize> = (0..8)
+            .map(|_| rng.random_range(0..=FIELD_ELEMENTS_PER_BLOB))
+            .chain([0, FIELD_ELEMENTS_PER_BLOB])
+            .collect();
+        bounds.sort_unstable();
+        let mut builder = CommitmentBuilder::new(&kzg_settings);
+        for window in bounds.windows(2).rev() {
+            builder
+                .update(window[0], &field_elements[window[0]..window[1]])
+                .unwrap();
+        }
+        assert!((0..FIELD_ELEMENTS_PER_BLOB).all(|i| builder.is_provided(i)));
+        assert_eq!(builder.finalize().bytes, expected.bytes);
 
-    const COMPUTE_CELLS_TESTS: &str = "tests/compute_cells/*/*/*";
-    const COMPUTE_CELLS_AND_KZG_PROOFS_TESTS: &str = "tests/compute_cells_and_kzg_proofs/*/*/*";
-    const RECOVER_CELLS_AND_KZG_PROOFS_TESTS: &str = "tests/recover_cells_and_kzg_proofs/*/*/*";
-    const VERIFY_CELL_KZG_PROOF_BATCH_TESTS: &str = "tests/verify_cell_kzg_proof_batch/*/*/*";
+        // Field elements which are never provided are zero.
+        let empty = CommitmentBuilder::new(&kzg_settings).finalize();
+        let zero_blob = Blob::default();
+        let expected = kzg_settings.blob_to_kzg_commitment(&zero_blob).unwrap();
+        assert_eq!(empty.bytes, expected.bytes);
+    }
 
     #[test]
-    fn test_blob_to_kzg_commitment() {
+    fn test_commitment_builder_invalid_chunks() {
+        let mut rng = rand::rng();
         let trusted_setup_file = Path::new("src/trusted_setup.txt");
         assert!(trusted_setup_file.exists());
         let kzg_settings = KZGSettings::load_trusted_setup_file(trusted_setup_file, 0).unwrap();
-        let test_files: Vec<PathBuf> = glob::glob(BLOB_TO_KZG_COMMITMENT_TESTS)
-            .unwrap()
-            .map(Result::unwrap)
+
+        let blob = generate_random_blob(&mut rng);
+        let field_elements: Vec<Bytes32> = blob
+            .chunks(BYTES_PER_FIELD_ELEMENT)
+            .take(16)
+            .map(|chunk| Bytes32::from_bytes(chunk).unwrap())
             .collect();
-        assert!(!test_files.is_empty());
+        let mut builder = CommitmentBuilder::new(&kzg_settings);
+        builder.update(8, &field_elements[..8]).unwrap();
 
-        #[allow(unused_variables)]
-        for (index, test_file) in test_files.iter().enumerate() {
-            let yaml_data = fs::read_to_string(test_file).unwrap();
-            let test: blob_to_kzg_commitment_test::Test = serde_yaml::from_str(&yaml_data).unwrap();
-            let Ok(blob) = test.input.get_blob() else {
-                assert!(test.get_output().is_none());
-                continue;
-            };
+        // Overlapping a previous chunk.
+        let error = builder.update(4, &field_elements[..8]).unwrap_err();
+        assert!(matches!(error, Error::InvalidChunk(_)));
 
-            #[cfg(feature = "generate-fuzz-corpus")]
-            {
-                use std::{env, fs::File, io::Write};
-                let root_dir = PathBuf::from(env::var("CARGO_MANIFEST_DIR").unwrap());
-                let dir_path = root_dir
-                    .join("fuzz")
-                    .join("corpus")
-                    .join("fuzz_blob_to_kzg_commitment");
-                fs::create_dir_all(&dir_path).unwrap();
-                let file_path = dir_path.join(format!("data_{index}.bin"));
-                let mut file = File::create(&file_path).unwrap();
-                file.write_all(&blob.bytes).unwrap();
-            }
+        // Past the end of the blob.
+        let error = builder
+            .update(FIELD_ELEMENTS_PER_BLOB - 4, &field_elements[..8])
+            .unwrap_err();
+        assert!(matches!(error, Error::InvalidChunk(_)));
 
-            match kzg_settings.blob_to_kzg_commitment(&blob) {
-                Ok(res) => assert_eq!(res.bytes, test.get_output().unwrap().bytes),
-                _ => assert!(test.get_output().is_none()),
-            }
-        }
+        // Non-canonical field element, the builder is left unchanged.
+        let mut non_canonical = field_elements.clone();
+        non_canonical[3] = Bytes32::new([0xff; 32]);
+        let error = builder.update(0, &non_canonical[..8]).unwrap_err();
+        assert!(matches!(error, Error::CError(C_KZG_RET::C_KZG_BADARGS)));
+        assert!(!builder.is_provided(0));
+        builder.update(0, &field_elements[..8]).unwrap();
     }
 
     #[test]
-    fn test_parse_kzg_trusted_setup() {
+    fn test_blob_to_kzg_commitment_and_proof() {
+        let mut rng = rand::rng();
         let trusted_setup_file = Path::new("src/trusted_setup.txt");
         assert!(trusted_setup_file.exists());
-        let trusted_setup = fs::read_to_string(trusted_setup_file).unwrap();
-        let _ = KZGSettings::parse_kzg_trusted_setup(&trusted_setup, 0).unwrap();
+        let kzg_settings = KZGSettings::load_trusted_setup_file(trusted_setup_file, 0).unwrap();
+
+        let blob = generate_random_blob(&mut rng);
+        let (commitment, proof) = kzg_settings
+            .blob_to_kzg_commitment_and_proof(&blob)
+            .unwrap();
+        let expected_commitment = kzg_settings.blob_to_kzg_commitment(&blob).unwrap();
+        let expected_proof = kzg_settings
+            .compute_blob_kzg_proof(&blob, &expected_commitment.to_bytes())
+            .unwrap();
+        assert_eq!(commitment.bytes, expected_commitment.bytes);
+        assert_eq!(proof.bytes, expected_proof.bytes);
+        assert!(kzg_settings
+            .verify_blob_kzg_proof(&blob, &commitment.to_bytes(), &proof.to_bytes())
+            .unwrap());
+
+        let mut invalid_blob = Blob::default();
+        invalid_blob[..BYTES_PER_FIELD_ELEMENT].copy_from_slice(&[0xff; BYTES_PER_FIELD_ELEMENT]);
+        let error = kzg_settings
+            .blob_to_kzg_commitment_and_proof(&invalid_blob)
+            .unwrap_err();
+        assert!(matches!(error, Error::CError(C_KZG_RET::C_KZG_BADARGS)));
     }
 
     #[test]
-    fn test_compute_kzg_proof() {
+    fn test_compute_cell_kzg_proofs() {
+        let mut rng = rand::rng();
         let trusted_setup_file = Path::new("src/trusted_setup.txt");
         assert!(trusted_setup_file.exists());
         let kzg_settings = KZGSettings::load_trusted_setup_file(trusted_setup_file, 0).unwrap();
-        let test_files: Vec<PathBuf> = glob::glob(COMPUTE_KZG_PROOF_TESTS)
-            .unwrap()
-            .map(Result::unwrap)
-            .collect();
-        assert!(!test_files.is_empty());
 
-        #[allow(unused_variables)]
-        for (index, test_file) in test_files.iter().enumerate() {
-            let yaml_data = fs::read_to_string(test_file).unwrap();
-            let test: compute_kzg_proof::Test = serde_yaml::from_str(&yaml_data).unwrap();
-            let (Ok(blob), Ok(z)) = (test.input.get_blob(), test.input.get_z()) else {
-                assert!(test.get_output().is_none());
-                continue;
-            };
+        let blob = generate_random_blob(&mut rng);
+        let (_, all_proofs) = kzg_settings.compute_cells_and_kzg_proofs(&blob).unwrap();
 
-            #[cfg(feature = "generate-fuzz-corpus")]
-            {
-                use std::{env, fs::File, io::Write};
-                let root_dir = PathBuf::from(env::var("CARGO_MANIFEST_DIR").unwrap());
-                let dir_path = root_dir
-                    .join("fuzz")
-                    .join("corpus")
-                    .join("fuzz_compute_kzg_proof");
-                fs::create_dir_all(&dir_path).unwrap();
-                let file_path = dir_path.join(format!("data_{index}.bin"));
-                let mut file = File::create(&file_path).unwrap();
-                file.write_all(&blob.bytes).unwrap();
-                file.write_all(&z.bytes).unwrap();
+        // A single sample and a custody-sized selection take different paths.
+        let sample = [rng.random_range(0..CELLS_PER_EXT_BLOB as u64)];
+        let custody: Vec<u64> = (0..CELLS_PER_EXT_BLOB as u64).step_by(8).collect();
+        for cell_indices in [&sample[..], &custody[..]] {
+            let proofs = kzg_settings
+                .compute_cell_kzg_proofs(&blob, cell_indices)
+                .unwrap();
+            assert_eq!(proofs.len(), cell_indices.len());
+            for (proof, &index) in proofs.iter().zip(cell_indices) {
+                assert_eq!(proof.bytes, all_proofs[index as usize].bytes);
             }
+        }
 
-            match kzg_settings.compute_kzg_proof(&blob, &z) {
-                Ok((proof, y)) => {
-                    assert_eq!(proof.bytes, test.get_output().unwrap().0.bytes);
-                    assert_eq!(y.bytes, test.get_output().unwrap().1.bytes);
-                }
-                _ => assert!(test.get_output().is_none()),
-            }
+        assert!(kzg_settings
+            .compute_cell_kzg_proofs(&blob, &[])
+            .unwrap()
+            .is_empty());
+        let error = kzg_settings
+            .compute_cell_kzg_proofs(&blob, &[CELLS_PER_EXT_BLOB as u64])
+            .unwrap_err();
+        assert!(matches!(error, Error::CError(C_KZG_RET::C_KZG_BADARGS)));
+    }
+
+    #[test]
+    fn test_cells_to_blob() {
+        let mut rng = rand::rng();
+        let trusted_setup_file = Path::new("src/trusted_setup.txt");
+        assert!(trusted_setup_file.exists());
+        let kzg_settings = KZGSettings::load_trusted_setup_file(trusted_setup_file, 0).unwrap();
+
+        let blob = generate_random_blob(&mut rng);
+        let cells = kzg_settings.compute_cells(&blob).unwrap();
+
+        // The first half of the cells, and the second half which needs recovery.
+        let half = CELLS_PER_EXT_BLOB / 2;
+        for range in [0..half, half..CELLS_PER_EXT_BLOB] {
+            let cell_indices: Vec<u64> = range.clone().map(|i| i as u64).collect();
+            let result = kzg_settings
+                .cells_to_blob(&cell_indices, &cells[range])
+                .unwrap();
+            assert_eq!(*result, blob);
         }
+
+        let error = kzg_settings
+            .cells_to_blob(&[0, 1], &cells[..2])
+            .unwrap_err();
+        assert!(matches!(error, Error::CError(C_KZG_RET::C_KZG_BADARGS)));
+        let error = kzg_settings.cells_to_blob(&[0], &cells[..2]).unwrap_err();
+        assert!(matches!(error, Error::MismatchLength(_)));
     }
 
     #[test]
-    fn test_compute_blob_kzg_proof() {
+    fn test_recover_cells_and_kzg_proofs_lenient() {
+        let mut rng = rand::rng();
         let trusted_setup_file = Path::new("src/trusted_setup.txt");
         assert!(trusted_setup_file.exists());
         let kzg_settings = KZGSettings::load_trusted_setup_file(trusted_setup_file, 0).unwrap();
-        let test_files: Vec<PathBuf> = glob::glob(COMPUTE_BLOB_KZG_PROOF_TESTS)
-            .unwrap()
-            .map(Result::unwrap)
+
+        let blob = generate_random_blob(&mut rng);
+        let (cells, proofs) = kzg_settings.compute_cells_and_kzg_proofs(&blob).unwrap();
+
+        // Every other cell in descending order, with some of them repeated.
+        let mut cell_indices: Vec<u64> = (0..CELLS_PER_EXT_BLOB)
+            .step_by(2)
+            .rev()
+            .map(|i| i as u64)
             .collect();
-        assert!(!test_files.is_empty());
+        cell_indices.extend([0, 64, 126]);
+        let partial_cells: Vec<Cell> = cell_indices.iter().map(|&i| cells[i as usize]).collect();
+        let (recovered_cells, recovered_proofs) = kzg_settings
+            .recover_cells_and_kzg_proofs_lenient(&cell_indices, &partial_cells)
+            .unwrap();
+        assert_eq!(recovered_cells, cells);
+        assert!(recovered_proofs
+            .iter()
+            .zip(proofs.iter())
+            .all(|(a, b)| a.bytes == b.bytes));
 
-        #[allow(unused_variables)]
-        for (index, test_file) in test_files.iter().enumerate() {
-            let yaml_data = fs::read_to_string(test_file).unwrap();
-            let test: compute_blob_kzg_proof::Test = serde_yaml::from_str(&yaml_data).unwrap();
-            let (Ok(blob), Ok(commitment)) = (test.input.get_blob(), test.input.get_commitment())
-            else {
-                assert!(test.get_output().is_none());
-                continue;
-            };
+        // The strict version rejects the same input.
+        let error = kzg_settings
+            .recover_cells_and_kzg_proofs(&cell_indices, &partial_cells)
+            .unwrap_err();
+        assert!(matches!(error, Error::CError(C_KZG_RET::C_KZG_BADARGS)));
 
-            #[cfg(feature = "generate-fuzz-corpus")]
-            {
-                use std::{env, fs::File, io::Write};
-                let root_dir = PathBuf::from(env::var("CARGO_MANIFEST_DIR").unwrap());
-                let dir_path = root_dir
-                    .join("fuzz")
-                    .join("corpus")
-                    .join("fuzz_compute_blob_kzg_proof");
-                fs::create_dir_all(&dir_path).unwrap();
-                let file_path = dir_path.join(format!("data_{index}.bin"));
-                let mut file = File::create(&file_path).unwrap();
-                file.write_all(&blob.bytes).unwrap();
-                file.write_all(&commitment.bytes).unwrap();
-            }
+        // A repeated index with a different cell is a conflict.
+        let mut conflicting_cells = partial_cells.clone();
+        *conflicting_cells.last_mut().unwrap() = cells[127];
+        let error = kzg_settings
+            .recover_cells_and_kzg_proofs_lenient(&cell_indices, &conflicting_cells)
+            .unwrap_err();
+        assert!(matches!(error, Error::ConflictingCells(_)));
+    }
 
-            match kzg_settings.compute_blob_kzg_proof(&blob, &commitment) {
-                Ok(res) => assert_eq!(res.bytes, test.get_output().unwrap().bytes),
-                _ => assert!(test.get_output().is_none()),
-            }
-        }
+    #[test]
+    fn test_recover_cells() {
+        let mut rng = rand::rng();
+        let trusted_setup_file = Path::new("src/trusted_setup.txt");
+        assert!(trusted_setup_file.exists());
+        let kzg_settings = KZGSettings::load_trusted_setup_file(trusted_setup_file, 0).unwrap();
+
+        let blob = generate_random_blob(&mut rng);
+        let cells = kzg_settings.compute_cells(&blob).unwrap();
+
+        let cell_indices: Vec<u64> = (0..CELLS_PER_EXT_BLOB as u64).skip(1).step_by(2).collect();
+        let partial_cells: Vec<Cell> = cell_indices.iter().map(|&i| cells[i as usize]).collect();
+        let recovered_cells = kzg_settings
+            .recover_cells(&cell_indices, &partial_cells)
+            .unwrap();
+        assert_eq!(recovered_cells, cells);
+
+        let error = kzg_settings
+            .recover_cells(&cell_indices[1..], &partial_cells[1..])
+            .unwrap_err();
+        assert!(matches!(error, Error::CError(C_KZG_RET::C_KZG_BADARGS)));
     }
 
     #[test]
-    fn test_verify_kzg_proof() {
+    fn test_recover_columns() {
+        let mut rng = rand::rng();
         let trusted_setup_file = Path::new("src/trusted_setup.txt");
         assert!(trusted_setup_file.exists());
         let kzg_settings = KZGSettings::load_trusted_setup_file(trusted_setup_file, 0).unwrap();
-        let test_files: Vec<PathBuf> = glob::glob(VERIFY_KZG_PROOF_TESTS)
-            .unwrap()
-            .map(Result::unwrap)
-            .collect();
-        assert!(!test_files.is_empty());
 
-        #[allow(unused_variables)]
-        for (index, test_file) in test_files.iter().enumerate() {
-            let yaml_data = fs::read_to_string(test_file).unwrap();
-            let test: verify_kzg_proof::Test = serde_yaml::from_str(&yaml_data).unwrap();
-            let (Ok(commitment), Ok(z), Ok(y), Ok(proof)) = (
-                test.input.get_commitment(),
-                test.input.get_z(),
-                test.input.get_y(),
-                test.input.get_proof(),
-            ) else {
-                assert!(test.get_output().is_none());
-                continue;
-            };
+        let blobs: Vec<Blob> = (0..3).map(|_| generate_random_blob(&mut rng)).collect();
+        let data_columns = kzg_settings.compute_data_columns(&blobs).unwrap();
 
-            #[cfg(feature = "generate-fuzz-corpus")]
-            {
-                use std::{env, fs::File, io::Write};
-                let root_dir = PathBuf::from(env::var("CARGO_MANIFEST_DIR").unwrap());
-                let dir_path = root_dir
-                    .join("fuzz")
-                    .join("corpus")
-                    .join("fuzz_verify_kzg_proof");
-                fs::create_dir_all(&dir_path).unwrap();
-                let file_path = dir_path.join(format!("data_{index}.bin"));
-                let mut file = File::create(&file_path).unwrap();
-                file.write_all(&commitment.bytes).unwrap();
-                file.write_all(&z.bytes).unwrap();
-                file.write_all(&y.bytes).unwrap();
-                file.write_all(&proof.bytes).unwrap();
+        // Receive half of the columns in descending order, with a repeated column.
+        let mut received_columns: Vec<DataColumn> = data_columns[CELLS_PER_EXT_BLOB / 2..]
+            .iter()
+            .rev()
+            .cloned()
+            .collect();
+        received_columns.push(received_columns[0].clone());
+        let recovered_columns = kzg_settings.recover_columns(&received_columns).unwrap();
+        assert_eq!(recovered_columns.len(), CELLS_PER_EXT_BLOB);
+        for (recovered, expected) in recovered_columns.iter().zip(&data_columns) {
+            assert_eq!(recovered.index, expected.index);
+            assert_eq!(recovered.cells, expected.cells);
+            for (a, b) in recovered.proofs.iter().zip(&expected.proofs) {
+                assert_eq!(a.bytes, b.bytes);
+            }
+            for (a, b) in recovered.commitments.iter().zip(&expected.commitments) {
+                assert_eq!(a.bytes, b.bytes);
             }
+        }
+
+        // Not enough columns, even with a repeated one.
+        let mut too_few = received_columns[2..].to_vec();
+        let error = kzg_settings.recover_columns(&too_few).unwrap_err();
+        assert!(matches!(error, Error::InvalidCellIndex(_)));
+        too_few.truncate(0);
+        let error = kzg_settings.recover_columns(&too_few).unwrap_err();
+        assert!(matches!(error, Error::InvalidCellIndex(_)));
+
+        // Columns with different numbers of cells.
+        let mut uneven_columns = received_columns.clone();
+        uneven_columns[1].cells.pop();
+        let error = kzg_settings.recover_columns(&uneven_columns).unwrap_err();
+        assert!(matches!(error, Error::MismatchLength(_)));
+
+        // Columns with different commitments.
+        let mut inconsistent_columns = received_columns.clone();
+        inconsistent_columns[1].commitments.swap(0, 1);
+        let error = kzg_settings
+            .recover_columns(&inconsistent_columns)
+            .unwrap_err();
+        assert!(matches!(error, Error::InconsistentCells(_)));
+
+        // A block without blobs still needs valid columns.
+        let empty_columns = kzg_settings.compute_data_columns(&[]).unwrap();
+        let recovered_columns = kzg_settings
+            .recover_columns(&empty_columns[..CELLS_PER_EXT_BLOB / 2])
+            .unwrap();
+        assert_eq!(recovered_columns.len(), CELLS_PER_EXT_BLOB);
+        assert!(recovered_columns
+            .iter()
+            .all(|column| column.cells.is_empty()));
+        let error = kzg_settings
+            .recover_columns(&empty_columns[1..CELLS_PER_EXT_BLOB / 2])
+            .unwrap_err();
+        assert!(matches!(error, Error::InvalidCellIndex(_)));
+        let mut invalid_columns = empty_columns[..CELLS_PER_EXT_BLOB / 2].to_vec();
+        invalid_columns[0].index = CELLS_PER_EXT_BLOB as u64;
+        let error = kzg_settings.recover_columns(&invalid_columns).unwrap_err();
+        assert!(matches!(error, Error::InvalidCellIndex(_)));
+    }
 
-            match kzg_settings.verify_kzg_proof(&commitment, &z, &y, &proof) {
-                Ok(res) => assert_eq!(res, test.get_output().unwrap()),
-                _ => assert!(test.get_output().is_none()),
+    #[test]
+    fn test_compute_data_columns() {
+        let mut rng = rand::rng();
+        let trusted_setup_file = Path::new("src/trusted_setup.txt");
+        assert!(trusted_setup_file.exists());
+        let kzg_settings = KZGSettings::load_trusted_setup_file(trusted_setup_file, 0).unwrap();
+
+        let blobs: Vec<Blob> = (0..2).map(|_| generate_random_blob(&mut rng)).collect();
+        let data_columns = kzg_settings.compute_data_columns(&blobs).unwrap();
+        assert_eq!(data_columns.len(), CELLS_PER_EXT_BLOB);
+
+        for (blob_index, blob) in blobs.iter().enumerate() {
+            let commitment = kzg_settings.blob_to_kzg_commitment(blob).unwrap();
+            let (cells, proofs) = kzg_settings.compute_cells_and_kzg_proofs(blob).unwrap();
+            for (i, data_column) in data_columns.iter().enumerate() {
+                assert_eq!(data_column.index, i as u64);
+                assert_eq!(data_column.cells[blob_index], cells[i]);
+                assert_eq!(data_column.proofs[blob_index].bytes, proofs[i].bytes);
+                assert_eq!(data_column.commitments[blob_index].bytes, commitment.bytes);
             }
         }
+
+        // A block without blobs still has empty columns.
+        let data_columns = kzg_settings.compute_data_columns(&[]).unwrap();
+        assert_eq!(data_columns.len(), CELLS_PER_EXT_BLOB);
+        assert!(data_columns
+            .iter()
+            .all(|data_column| data_column.cells.is_empty()));
     }
 
     #[test]
-    fn test_verify_blob_kzg_proof() {
+    fn test_verify_data_column() {
+        let mut rng = rand::rng();
         let trusted_setup_file = Path::new("src/trusted_setup.txt");
         assert!(trusted_setup_file.exists());
         let kzg_settings = KZGSettings::load_trusted_setup_file(trusted_setup_file, 0).unwrap();
-        let test_files: Vec<PathBuf> = glob::glob(VERIFY_BLOB_KZG_PROOF_TESTS)
-            .unwrap()
-            .map(Result::unwrap)
+
+        let blobs: Vec<Blob> = (0..2).map(|_| generate_random_blob(&mut rng)).collect();
+        let data_columns = kzg_settings.compute_data_columns(&blobs).unwrap();
+        let data_column = &data_columns[rng.random_range(0..CELLS_PER_EXT_BLOB)];
+        let commitments: Vec<Bytes48> = data_column
+            .commitments
+            .iter()
+            .map(|commitment| commitment.to_bytes())
+            .collect();
+        let proofs: Vec<Bytes48> = data_column
+            .proofs
+            .iter()
+            .map(|proof| proof.to_bytes())
             .collect();
-        assert!(!test_files.is_empty());
 
-        #[allow(unused_variables)]
-        for (index, test_file) in test_files.iter().enumerate() {
-            let yaml_data = fs::read_to_string(test_file).unwrap();
-            let test: verify_blob_kzg_proof::Test = serde_yaml::from_str(&yaml_data).unwrap();
-            let (Ok(blob), Ok(commitment), Ok(proof)) = (
-                test.input.get_blob(),
-                test.input.get_commitment(),
-                test.input.get_proof(),
-            ) else {
-                assert!(test.get_output().is_none());
-                continue;
-            };
+        assert!(kzg_settings
+            .verify_data_column(data_column.index, &commitments, &data_column.cells, &proofs)
+            .unwrap());
 
-            #[cfg(feature = "generate-fuzz-corpus")]
-            {
-                use std::{env, fs::File, io::Write};
-                let root_dir = PathBuf::from(env::var("CARGO_MANIFEST_DIR").unwrap());
-                let dir_path = root_dir
-                    .join("fuzz")
-                    .join("corpus")
-                    .join("fuzz_verify_blob_kzg_proof");
-                fs::create_dir_all(&dir_path).unwrap();
-                let file_path = dir_path.join(format!("data_{index}.bin"));
-                let mut file = File::create(&file_path).unwrap();
-                file.write_all(&blob.bytes).unwrap();
-                file.write_all(&commitment.bytes).unwrap();
-                file.write_all(&proof.bytes).unwrap();
-            }
+        // The cells do not belong to this column.
+        let other_index = (data_column.index + 1) % CELLS_PER_EXT_BLOB as u64;
+        assert!(!kzg_settings
+            .verify_data_column(other_index, &commitments, &data_column.cells, &proofs)
+            .unwrap());
 
-            match kzg_settings.verify_blob_kzg_proof(&blob, &commitment, &proof) {
-                Ok(res) => assert_eq!(res, test.get_output().unwrap()),
-                _ => assert!(test.get_output().is_none()),
-            }
+        let error = kzg_settings
+            .verify_data_column(
+                CELLS_PER_EXT_BLOB as u64,
+                &commitments,
+                &data_column.cells,
+                &proofs,
+            )
+            .unwrap_err();
+        assert!(matches!(error, Error::InvalidCellIndex(_)));
+        let error = kzg_settings
+            .verify_data_column(
+                data_column.index,
+                &commitments[1..],
+                &data_column.cells,
+                &proofs,
+            )
+            .unwrap_err();
+        assert!(matches!(error, Error::MismatchLength(_)));
+    }
+
+    #[test]
+    fn test_extended_blob() {
+        let mut rng = rand::rng();
+        let trusted_setup_file = Path::new("src/trusted_setup.txt");
+        assert!(trusted_setup_file.exists());
+        let kzg_settings = KZGSettings::load_trusted_setup_file(trusted_setup_file, 0).unwrap();
+
+        let blob = generate_random_blob(&mut rng);
+        let extended_blob = kzg_settings.compute_extended_blob(&blob).unwrap();
+        assert_eq!(
+            extended_blob.field_elements().count(),
+            FIELD_ELEMENTS_PER_EXT_BLOB
+        );
+
+        // The first half is the original blob.
+        let blob_field_elements = blob
+            .chunks(BYTES_PER_FIELD_ELEMENT)
+            .map(|chunk| Bytes32::from_bytes(chunk).unwrap());
+        assert!(extended_blob
+            .field_elements()
+            .zip(blob_field_elements)
+            .all(|(a, b)| a == b));
+
+        // Each field element is the evaluation at its point.
+        for cell_index in [0, rng.random_range(1..CELLS_PER_EXT_BLOB as u64)] {
+            let offset = rng.random_range(0..FIELD_ELEMENTS_PER_CELL);
+            let z = kzg_settings
+                .cell_evaluation_point(cell_index, offset)
+                .unwrap();
+            let (_, y) = kzg_settings.compute_kzg_proof(&blob, &z).unwrap();
+            let cell = &extended_blob.cells()[cell_index as usize];
+            assert_eq!(cell.field_elements().nth(offset).unwrap(), y);
         }
+
+        assert!(kzg_settings
+            .cell_evaluation_point(CELLS_PER_EXT_BLOB as u64, 0)
+            .is_none());
+        assert!(kzg_settings
+            .cell_evaluation_point(0, FIELD_ELEMENTS_PER_CELL)
+            .is_none());
     }
 
     #[test]
-    fn test_verify_blob_kzg_proof_batch() {
+    fn test_blob_transaction_sidecar() {
+        let mut rng = rand::rng();
         let trusted_setup_file = Path::new("src/trusted_setup.txt");
         assert!(trusted_setup_file.exists());
         let kzg_settings = KZGSettings::load_trusted_setup_file(trusted_setup_file, 0).unwrap();
-        let test_files: Vec<PathBuf> = glob::glob(VERIFY_BLOB_KZG_PROOF_BATCH_TESTS)
+
+        let blobs: Vec<Blob> = (0..2).map(|_| generate_random_blob(&mut rng)).collect();
+        let (commitments, proofs) = blobs
+            .iter()
+            .map(|blob| {
+                let (commitment, proof) =
+                    kzg_settings.blob_to_kzg_commitment_and_proof(blob).unwrap();
+                (commitment.to_bytes(), proof.to_bytes())
+            })
+            .unzip();
+        let sidecar = BlobTransactionSidecar {
+            blobs,
+            commitments,
+            proofs,
+        };
+        assert!(kzg_settings
+            .verify_blob_transaction_sidecar(&sidecar)
+            .unwrap());
+
+        let sidecar_v1 = kzg_settings
+            .convert_blob_transaction_sidecar(&sidecar)
+            .unwrap();
+        assert_eq!(sidecar_v1.blobs, sidecar.blobs);
+        assert_eq!(sidecar_v1.commitments, sidecar.commitments);
+        assert_eq!(
+            sidecar_v1.cell_proofs.len(),
+            sidecar.blobs.len() * CELLS_PER_EXT_BLOB
+        );
+        assert!(kzg_settings
+            .verify_blob_transaction_sidecar_v1(&sidecar_v1)
+            .unwrap());
+
+        // A cell proof for the wrong cell.
+        let mut invalid_sidecar_v1 = sidecar_v1.clone();
+        invalid_sidecar_v1.cell_proofs.swap(0, 1);
+        assert!(!kzg_settings
+            .verify_blob_transaction_sidecar_v1(&invalid_sidecar_v1)
+            .unwrap());
+
+        // A missing cell proof.
+        let mut invalid_sidecar_v1 = sidecar_v1;
+        invalid_sidecar_v1.cell_proofs.pop();
+        let error = kzg_settings
+            .verify_blob_transaction_sidecar_v1(&invalid_sidecar_v1)
+            .unwrap_err();
+        assert!(matches!(error, Error::MismatchLength(_)));
+
+        // The blob proofs are swapped, so they do not verify.
+        let mut invalid_sidecar = sidecar;
+        invalid_sidecar.proofs.swap(0, 1);
+        let error = kzg_settings
+            .convert_blob_transaction_sidecar(&invalid_sidecar)
+            .unwrap_err();
+        assert!(matches!(error, Error::InvalidKzgProof(_)));
+    }
+
+    #[test]
+    fn test_recover_cells_and_kzg_proofs_checked() {
+        let mut rng = rand::rng();
+        let trusted_setup_file = Path::new("src/trusted_setup.txt");
+        assert!(trusted_setup_file.exists());
+        let kzg_settings = KZGSettings::load_trusted_setup_file(trusted_setup_file, 0).unwrap();
+
+        let blob = generate_random_blob(&mut rng);
+        let other_blob = generate_random_blob(&mut rng);
+        let commitment = kzg_settings
+            .blob_to_kzg_commitment(&blob)
             .unwrap()
-            .map(Result::unwrap)
+            .to_bytes();
+        let (cells, proofs) = kzg_settings.compute_cells_and_kzg_proofs(&blob).unwrap();
+        let other_cells = kzg_settings.compute_cells(&other_blob).unwrap();
+
+        let half = CELLS_PER_EXT_BLOB / 2;
+        let cell_indices: Vec<u64> = (0..=half as u64).collect();
+        let (recovered_cells, recovered_proofs) = kzg_settings
+            .recover_cells_and_kzg_proofs_checked(&cell_indices, &cells[..=half], Some(&commitment))
+            .unwrap();
+        assert_eq!(recovered_cells, cells);
+        assert!(recovered_proofs
+            .iter()
+            .zip(proofs.iter())
+            .all(|(a, b)| a.bytes == b.bytes));
+
+        // One more cell than needed, from another blob. This is caught both when the first half
+        // is complete and when it is not.
+        let mut mixed_cells = cells[..=half].to_vec();
+        mixed_cells[half] = other_cells[half];
+        let error = kzg_settings
+            .recover_cells_and_kzg_proofs_checked(&cell_indices, &mixed_cells, None)
+            .unwrap_err();
+        assert!(matches!(error, Error::InconsistentCells(_)));
+        let shifted_indices: Vec<u64> = (1..=half as u64 + 1).collect();
+        let mut shifted_cells = cells[1..=half + 1].to_vec();
+        shifted_cells[half] = other_cells[half + 1];
+        let error = kzg_settings
+            .recover_cells_and_kzg_proofs_checked(&shifted_indices, &shifted_cells, None)
+            .unwrap_err();
+        assert!(matches!(error, Error::InconsistentCells(_)));
+
+        // Exactly enough cells, so only the commitment can tell.
+        let (recovered_cells, _) = kzg_settings
+            .recover_cells_and_kzg_proofs_checked(&cell_indices[1..], &mixed_cells[1..], None)
+            .unwrap();
+        assert_ne!(recovered_cells, cells);
+        let error = kzg_settings
+            .recover_cells_and_kzg_proofs_checked(
+                &cell_indices[1..],
+                &mixed_cells[1..],
+                Some(&commitment),
+            )
+            .unwrap_err();
+        assert!(matches!(error, Error::InconsistentCells(_)));
+    }
+
+    #[test]
+    fn test_verify_and_recover() {
+        let mut rng = rand::rng();
+        let trusted_setup_file = Path::new("src/trusted_setup.txt");
+        assert!(trusted_setup_file.exists());
+        let kzg_settings = KZGSettings::load_trusted_setup_file(trusted_setup_file, 0).unwrap();
+
+        let blob = generate_random_blob(&mut rng);
+        let commitment = kzg_settings
+            .blob_to_kzg_commitment(&blob)
+            .unwrap()
+            .to_bytes();
+        let (cells, proofs) = kzg_settings.compute_cells_and_kzg_proofs(&blob).unwrap();
+
+        // Two more cells than needed, in descending order.
+        let half = CELLS_PER_EXT_BLOB / 2;
+        let mut cell_indices: Vec<u64> =
+            (half as u64 - 2..CELLS_PER_EXT_BLOB as u64).rev().collect();
+        let mut received_cells: Vec<Cell> =
+            cell_indices.iter().map(|&i| cells[i as usize]).collect();
+        let mut received_proofs: Vec<Bytes48> = cell_indices
+            .iter()
+            .map(|&i| proofs[i as usize].to_bytes())
             .collect();
-        assert!(!test_files.is_empty());
 
-        #[allow(unused_variables)]
-        for (index, test_file) in test_files.iter().enumerate() {
-            let yaml_data = fs::read_to_string(test_file).unwrap();
-            let test: verify_blob_kzg_proof_batch::Test = serde_yaml::from_str(&yaml_data).unwrap();
-            let (Ok(blobs), Ok(commitments), Ok(proofs)) = (
-                test.input.get_blobs(),
-                test.input.get_commitments(),
-                test.input.get_proofs(),
-            ) else {
-                assert!(test.get_output().is_none());
-                continue;
-            };
+        // A proof for another cell, and a malformed cell.
+        received_proofs[3] = proofs[0].to_bytes();
+        received_cells[40] = Cell::new([0xff; BYTES_PER_CELL]);
 
-            #[cfg(feature = "generate-fuzz-corpus")]
-            {
-                use std::{env, fs::File, io::Write};
-                let root_dir = PathBuf::from(env::var("CARGO_MANIFEST_DIR").unwrap());
-                let dir_path = root_dir
-                    .join("fuzz")
-                    .join("corpus")
-                    .join("fuzz_verify_blob_kzg_proof_batch");
-                fs::create_dir_all(&dir_path).unwrap();
-                let file_path = dir_path.join(format!("data_{index}.bin"));
-                let mut file = File::create(&file_path).unwrap();
-                for blob in &blobs {
-                    file.write_all(&blob.bytes).unwrap();
-                }
-                for commitment in &commitments {
-                    file.write_all(&commitment.bytes).unwrap();
-                }
-                for proof in &proofs {
-                    file.write_all(&proof.bytes).unwrap();
-                }
-            }
+        let result = kzg_settings
+            .verify_and_recover(
+                &commitment,
+                &cell_indices,
+                &received_cells,
+                &received_proofs,
+            )
+            .unwrap();
+        assert_eq!(result.rejected, vec![3, 40]);
+        assert_eq!(result.cells.unwrap(), cells);
+        assert!(result
+            .proofs
+            .unwrap()
+            .iter()
+            .zip(proofs.iter())
+            .all(|(a, b)| a.bytes == b.bytes));
+
+        // With an out of range index, too few valid cells remain.
+        cell_indices[0] = CELLS_PER_EXT_BLOB as u64;
+        let result = kzg_settings
+            .verify_and_recover(
+                &commitment,
+                &cell_indices,
+                &received_cells,
+                &received_proofs,
+            )
+            .unwrap();
+        assert_eq!(result.rejected, vec![0, 3, 40]);
+        assert!(result.cells.is_none());
+        assert!(result.proofs.is_none());
 
-            match kzg_settings.verify_blob_kzg_proof_batch(&blobs, &commitments, &proofs) {
-                Ok(res) => assert_eq!(res, test.get_output().unwrap()),
-                _ => assert!(test.get_output().is_none()),
-            }
-        }
+        // An invalid commitment is an error, not a reason to reject every cell.
+        let bad_commitment = Bytes48::new([0xff; BYTES_PER_COMMITMENT]);
+        assert!(kzg_settings
+            .verify_and_recover(
+                &bad_commitment,
+                &cell_indices,
+                &received_cells,
+                &received_proofs,
+            )
+            .is_err());
     }
 
     #[test]
-    fn test_compute_cells() {
+    fn test_validated_commitments_and_proofs() {
+        let mut rng = rand::rng();
         let trusted_setup_file = Path::new("src/trusted_setup.txt");
         assert!(trusted_setup_file.exists());
         let kzg_settings = KZGSettings::load_trusted_setup_file(trusted_setup_file, 0).unwrap();
-        let test_files: Vec<PathBuf> = glob::glob(COMPUTE_CELLS_TESTS)
-            .unwrap()
-            .map(Result::unwrap)
+
+        let blobs: Vec<Blob> = (0..3).map(|_| generate_random_blob(&mut rng)).collect();
+        let commitments: Vec<ValidatedKZGCommitment> = blobs
+            .iter()
+            .map(|blob| {
+                let commitment = kzg_settings.blob_to_kzg_commitment(blob).unwrap();
+                ValidatedKZGCommitment::try_from(commitment).unwrap()
+            })
+            .collect();
+        let proofs: Vec<ValidatedKZGProof> = blobs
+            .iter()
+            .zip(&commitments)
+            .map(|(blob, commitment)| {
+                let proof = kzg_settings
+                    .compute_blob_kzg_proof(blob, &commitment.to_bytes())
+                    .unwrap();
+                ValidatedKZGProof::try_from(proof).unwrap()
+            })
             .collect();
-        assert!(!test_files.is_empty());
 
-        #[allow(unused_variables)]
-        for (index, test_file) in test_files.iter().enumerate() {
-            let yaml_data = fs::read_to_string(test_file).unwrap();
-            let test: compute_cells::Test = serde_yaml::from_str(&yaml_data).unwrap();
-            let Ok(blob) = test.input.get_blob() else {
-                assert!(test.get_output().is_none());
-                continue;
-            };
+        assert!(kzg_settings
+            .verify_validated_blob_kzg_proof(&blobs[0], &commitments[0], &proofs[0])
+            .unwrap());
+        assert!(!kzg_settings
+            .verify_validated_blob_kzg_proof(&blobs[0], &commitments[0], &proofs[1])
+            .unwrap());
+        assert!(kzg_settings
+            .verify_validated_blob_kzg_proof_batch(&blobs, &commitments, &proofs)
+            .unwrap());
 
-            #[cfg(feature = "generate-fuzz-corpus")]
-            {
-                use std::{env, fs::File, io::Write};
-                let root_dir = PathBuf::from(env::var("CARGO_MANIFEST_DIR").unwrap());
-                let dir_path = root_dir
-                    .join("fuzz")
-                    .join("corpus")
-                    .join("fuzz_compute_cells");
-                fs::create_dir_all(&dir_path).unwrap();
-                let file_path = dir_path.join(format!("data_{index}.bin"));
-                let mut file = File::create(&file_path).unwrap();
-                file.write_all(&blob.bytes).unwrap();
-            }
+        let z = Bytes32::from([1; 32]);
+        let (proof, y) = kzg_settings.compute_kzg_proof(&blobs[1], &z).unwrap();
+        let proof = ValidatedKZGProof::new(&proof.to_bytes()).unwrap();
+        assert!(kzg_settings
+            .verify_validated_kzg_proof(&commitments[1], &z, &y, &proof)
+            .unwrap());
 
-            match kzg_settings.compute_cells(&blob) {
-                Ok(res) => assert_eq!(res.as_slice(), test.get_output().unwrap()),
-                _ => assert!(test.get_output().is_none()),
-            }
+        // The same commitment is reused for every cell.
+        let (cells, cell_proofs) = kzg_settings
+            .compute_cells_and_kzg_proofs(&blobs[2])
+            .unwrap();
+        let cell_indices: Vec<u64> = (0..CELLS_PER_EXT_BLOB as u64).step_by(3).collect();
+        let column_cells: Vec<Cell> = cell_indices.iter().map(|&i| cells[i as usize]).collect();
+        let column_proofs: Vec<ValidatedKZGProof> = cell_indices
+            .iter()
+            .map(|&i| ValidatedKZGProof::try_from(cell_proofs[i as usize]).unwrap())
+            .collect();
+        let column_commitments = vec![commitments[2]; cell_indices.len()];
+        assert!(kzg_settings
+            .verify_validated_cell_kzg_proof_batch(
+                &column_commitments,
+                &cell_indices,
+                &column_cells,
+                &column_proofs,
+            )
+            .unwrap());
+        assert!(!kzg_settings
+            .verify_validated_cell_kzg_proof_batch(
+                &column_commitments[1..],
+                &cell_indices[1..],
+                &column_cells[..column_cells.len() - 1],
+                &column_proofs[1..],
+            )
+            .unwrap());
+
+        // Points which are not on the curve are rejected.
+        assert!(ValidatedKZGCommitment::new(&Bytes48::from([0xff; 48])).is_err());
+        assert!(ValidatedKZGProof::from_bytes(&[0; 47]).is_err());
+    }
+
+    #[test]
+    fn test_blobs_bundle_validate() {
+        let mut rng = rand::rng();
+        let trusted_setup_file = Path::new("src/trusted_setup.txt");
+        assert!(trusted_setup_file.exists());
+        let kzg_settings = KZGSettings::load_trusted_setup_file(trusted_setup_file, 0).unwrap();
+
+        let blobs: Vec<Blob> = (0..2).map(|_| generate_random_blob(&mut rng)).collect();
+        let mut commitments = Vec::new();
+        let mut proofs = Vec::new();
+        let mut cell_proofs = Vec::new();
+        for blob in &blobs {
+            let (commitment, proof) = kzg_settings.blob_to_kzg_commitment_and_proof(blob).unwrap();
+            commitments.push(commitment.to_bytes());
+            proofs.push(proof.to_bytes());
+            let (_, blob_cell_proofs) = kzg_settings.compute_cells_and_kzg_proofs(blob).unwrap();
+            cell_proofs.extend(blob_cell_proofs.iter().map(KZGProof::to_bytes));
+        }
+
+        let mut bundle_v1 = BlobsBundleV1 {
+            commitments: commitments.clone(),
+            proofs,
+            blobs: blobs.clone(),
+        };
+        bundle_v1.validate(&kzg_settings).unwrap();
+        let mut bundle_v2 = BlobsBundleV2 {
+            commitments,
+            proofs: cell_proofs,
+            blobs,
+        };
+        bundle_v2.validate(&kzg_settings).unwrap();
+
+        // Proofs which do not verify.
+        bundle_v1.proofs.swap(0, 1);
+        assert!(matches!(
+            bundle_v1.validate(&kzg_settings),
+            Err(Error::InvalidKzgProof(_))
+        ));
+        bundle_v2.commitments.swap(0, 1);
+        assert!(matches!(
+            bundle_v2.validate(&kzg_settings),
+            Err(Error::InvalidKzgProof(_))
+        ));
+
+        // Wrong counts.
+        bundle_v1.proofs.pop();
+        assert!(matches!(
+            bundle_v1.validate(&kzg_settings),
+            Err(Error::MismatchLength(_))
+        ));
+        bundle_v2.proofs.pop();
+        assert!(matches!(
+            bundle_v2.validate(&kzg_settings),
+            Err(Error::MismatchLength(_))
+        ));
+    }
+
+    /// Returns the root of a tree of depth `depth` with the given leaves, and the branch for the
+    /// leaf at `index`.
+    fn merkle_root_and_branch(
+        mut layer: Vec<merkle::Chunk>,
+        depth: usize,
+        mut index: usize,
+    ) -> (merkle::Chunk, Vec<merkle::Chunk>) {
+        layer.resize(1 << depth, [0; 32]);
+        let mut branch = Vec::new();
+        for _ in 0..depth {
+            branch.push(layer[index ^ 1]);
+            layer = layer
+                .chunks_exact(2)
+                .map(|pair| merkle::hash_concat(&pair[0], &pair[1]))
+                .collect();
+            index /= 2;
         }
+        (layer[0], branch)
     }
 
     #[test]
-    fn test_compute_cells_and_kzg_proofs() {
+    fn test_blob_sidecar() {
+        let mut rng = rand::rng();
         let trusted_setup_file = Path::new("src/trusted_setup.txt");
         assert!(trusted_setup_file.exists());
         let kzg_settings = KZGSettings::load_trusted_setup_file(trusted_setup_file, 0).unwrap();
-        let test_files: Vec<PathBuf> = glob::glob(COMPUTE_CELLS_AND_KZG_PROOFS_TESTS)
+
+        let blobs: Vec<Blob> = (0..3).map(|_| generate_random_blob(&mut rng)).collect();
+        let commitments: Vec<KZGCommitment> = blobs
+            .iter()
+            .map(|blob| kzg_settings.blob_to_kzg_commitment(blob).unwrap())
+            .collect();
+
+        // Build a block body whose commitments list holds the commitments.
+        let index = 2;
+        let list_depth = MAX_BLOB_COMMITMENTS_PER_BLOCK.trailing_zeros() as usize;
+        let leaves = commitments
+            .iter()
+            .map(|c| merkle::byte_vector_root(&c.bytes))
+            .collect();
+        let (data_root, mut branch) = merkle_root_and_branch(leaves, list_depth, index);
+        branch.push(merkle::length_chunk(commitments.len()));
+        let mut body_fields: Vec<merkle::Chunk> = (0..12).map(|_| rng.random()).collect();
+        body_fields[BLOB_KZG_COMMITMENTS_FIELD_INDEX as usize] =
+            merkle::mix_in_length(&data_root, commitments.len());
+        let (body_root, body_branch) =
+            merkle_root_and_branch(body_fields, 4, BLOB_KZG_COMMITMENTS_FIELD_INDEX as usize);
+        branch.extend(body_branch);
+        let body_root = Bytes32::from(body_root);
+
+        let mut sidecar = BlobSidecar {
+            index: index as u64,
+            blob: blobs[index].clone(),
+            kzg_commitment: commitments[index],
+            kzg_proof: kzg_settings
+                .compute_blob_kzg_proof(&blobs[index], &commitments[index].to_bytes())
+                .unwrap(),
+            kzg_commitment_inclusion_proof: branch
+                .into_iter()
+                .map(Bytes32::from)
+                .collect::<Vec<_>>()
+                .try_into()
+                .unwrap(),
+        };
+        assert!(sidecar.verify_kzg(&kzg_settings).unwrap());
+        assert!(sidecar.verify_inclusion_proof(&body_root));
+        assert!(!sidecar.verify_inclusion_proof(&Bytes32::default()));
+
+        // The wrong position in the list.
+        sidecar.index = 1;
+        assert!(!sidecar.verify_inclusion_proof(&body_root));
+        sidecar.index = MAX_BLOB_COMMITMENTS_PER_BLOCK as u64 + index as u64;
+        assert!(!sidecar.verify_inclusion_proof(&body_root));
+        sidecar.index = index as u64;
+
+        // A commitment which is not in the block.
+        sidecar.kzg_commitment = commitments[0];
+        assert!(!sidecar.verify_inclusion_proof(&body_root));
+        assert!(!sidecar.verify_kzg(&kzg_settings).unwrap());
+    }
+
+    /// Returns the path and contents of every case of `handler` in the test vectors.
+    fn spec_test_cases(handler: SpecTestHandler) -> Vec<(PathBuf, String)> {
+        let pattern = format!("tests/{}/*/*/*", handler.name());
+        let cases: Vec<(PathBuf, String)> = glob::glob(&pattern)
             .unwrap()
             .map(Result::unwrap)
+            .map(|path| {
+                let yaml_data = fs::read_to_string(&path).unwrap();
+                (path, yaml_data)
+            })
             .collect();
-        assert!(!test_files.is_empty());
+        assert!(!cases.is_empty());
+        cases
+    }
 
-        #[allow(unused_variables)]
-        for (index, test_file) in test_files.iter().enumerate() {
-            let yaml_data = fs::read_to_string(test_file).unwrap();
-            let test: compute_cells_and_kzg_proofs::Test =
-                serde_yaml::from_str(&yaml_data).unwrap();
-            let Ok(blob) = test.input.get_blob() else {
-                assert!(test.get_output().is_none());
-                continue;
-            };
+    /// Runs every case of `handler` in the test vectors, and returns their contents.
+    fn run_spec_test_cases(handler: SpecTestHandler) -> Vec<String> {
+        let trusted_setup_file = Path::new("src/trusted_setup.txt");
+        assert!(trusted_setup_file.exists());
+        let kzg_settings = KZGSettings::load_trusted_setup_file(trusted_setup_file, 0).unwrap();
+        spec_test_cases(handler)
+            .into_iter()
+            .map(|(path, yaml_data)| {
+                let outcome = handler.run_case(&yaml_data, &kzg_settings);
+                assert_eq!(outcome, Ok(()), "{}", path.display());
+                yaml_data
+            })
+            .collect()
+    }
 
+    /// Creates the file of the `index`th corpus entry of a fuzz target.
+    #[cfg(feature = "generate-fuzz-corpus")]
+    fn fuzz_corpus_file(target: &str, index: usize) -> fs::File {
+        use std::env;
+        let root_dir = PathBuf::from(env::var("CARGO_MANIFEST_DIR").unwrap());
+        let dir_path = root_dir.join("fuzz").join("corpus").join(target);
+        fs::create_dir_all(&dir_path).unwrap();
+        fs::File::create(dir_path.join(format!("data_{index}.bin"))).unwrap()
+    }
+
+    #[test]
+    fn test_blob_to_kzg_commitment() {
+        #[allow(unused_variables)]
+        for (index, yaml_data) in run_spec_test_cases(SpecTestHandler::BlobToKzgCommitment)
+            .iter()
+            .enumerate()
+        {
             #[cfg(feature = "generate-fuzz-corpus")]
             {
-                use std::{env, fs::File, io::Write};
-                let root_dir = PathBuf::from(env::var("CARGO_MANIFEST_DIR").unwrap());
-                let dir_path = root_dir
-                    .join("fuzz")
-                    .join("corpus")
-                    .join("fuzz_compute_cells_and_kzg_proofs");
-                fs::create_dir_all(&dir_path).unwrap();
-                let file_path = dir_path.join(format!("data_{index}.bin"));
-                let mut file = File::create(&file_path).unwrap();
-                file.write_all(&blob.bytes).unwrap();
+                use std::io::Write;
+                let test: blob_to_kzg_commitment_test::Test =
+                    serde_yaml::from_str(yaml_data).unwrap();
+                if let Ok(blob) = test.input.get_blob() {
+                    let mut file = fuzz_corpus_file("fuzz_blob_to_kzg_commitment", index);
+                    file.write_all(&blob.bytes).unwrap();
+                }
             }
+        }
+    }
 
-            match kzg_settings.compute_cells_and_kzg_proofs(&blob) {
-                Ok((cells, proofs)) => {
-                    let (expected_cells, expected_proofs) = test.get_output().unwrap();
-                    assert_eq!(cells.as_slice(), expected_cells);
-                    let proofs_as_bytes: Vec<Bytes48> =
-                        proofs.iter().map(|p| p.to_bytes()).collect();
-                    assert_eq!(proofs_as_bytes, expected_proofs);
+    #[test]
+    fn test_parse_kzg_trusted_setup() {
+        let trusted_setup_file = Path::new("src/trusted_setup.txt");
+        assert!(trusted_setup_file.exists());
+        let trusted_setup = fs::read_to_string(trusted_setup_file).unwrap();
+
+        #[cfg(feature = "generate-fuzz-corpus")]
+        {
+            use std::io::Write;
+            let mut file = fuzz_corpus_file("fuzz_parse_kzg_trusted_setup", 0);
+            file.write_all(trusted_setup.as_bytes()).unwrap();
+        }
+
+        let _ = KZGSettings::parse_kzg_trusted_setup(&trusted_setup, 0).unwrap();
+    }
+
+    #[test]
+    fn test_compute_kzg_proof() {
+        #[allow(unused_variables)]
+        for (index, yaml_data) in run_spec_test_cases(SpecTestHandler::ComputeKzgProof)
+            .iter()
+            .enumerate()
+        {
+            #[cfg(feature = "generate-fuzz-corpus")]
+            {
+                use std::io::Write;
+                let test: compute_kzg_proof::Test = serde_yaml::from_str(yaml_data).unwrap();
+                if let (Ok(blob), Ok(z)) = (test.input.get_blob(), test.input.get_z()) {
+                    let mut file = fuzz_corpus_file("fuzz_compute_kzg_proof", index);
+                    file.write_all(&blob.bytes).unwrap();
+                    file.write_all(&z.bytes).unwrap();
                 }
-                _ => assert!(test.get_output().is_none()),
             }
         }
     }
 
     #[test]
-    fn test_recover_cells_and_kzg_proofs() {
+    fn test_compute_blob_kzg_proof() {
         let trusted_setup_file = Path::new("src/trusted_setup.txt");
-        assert!(trusted_setup_file.exists());
         let kzg_settings = KZGSettings::load_trusted_setup_file(trusted_setup_file, 0).unwrap();
-        let test_files: Vec<PathBuf> = glob::glob(RECOVER_CELLS_AND_KZG_PROOFS_TESTS)
-            .unwrap()
-            .map(Result::unwrap)
-            .collect();
-        assert!(!test_files.is_empty());
 
         #[allow(unused_variables)]
-        for (index, test_file) in test_files.iter().enumerate() {
-            let yaml_data = fs::read_to_string(test_file).unwrap();
-            let test: recover_cells_and_kzg_proofs::Test =
-                serde_yaml::from_str(&yaml_data).unwrap();
-            let (Ok(cell_indices), Ok(cells)) =
-                (test.input.get_cell_indices(), test.input.get_cells())
+        for (index, yaml_data) in run_spec_test_cases(SpecTestHandler::ComputeBlobKzgProof)
+            .iter()
+            .enumerate()
+        {
+            let test: compute_blob_kzg_proof::Test = serde_yaml::from_str(yaml_data).unwrap();
+            let (Ok(blob), Ok(commitment)) = (test.input.get_blob(), test.input.get_commitment())
             else {
-                assert!(test.get_output().is_none());
                 continue;
             };
 
             #[cfg(feature = "generate-fuzz-corpus")]
             {
-                use std::{env, fs::File, io::Write};
-                let root_dir = PathBuf::from(env::var("CARGO_MANIFEST_DIR").unwrap());
-                let dir_path = root_dir
-                    .join("fuzz")
-                    .join("corpus")
-                    .join("fuzz_recover_cells_and_kzg_proofs");
-                fs::create_dir_all(&dir_path).unwrap();
-                let file_path = dir_path.join(format!("data_{index}.bin"));
-                let mut file = File::create(&file_path).unwrap();
-                for cell_index in &cell_indices {
-                    file.write_all(&cell_index.to_le_bytes()).unwrap();
-                }
-                for cell in &cells {
-                    file.write_all(&cell.bytes).unwrap();
+                use std::io::Write;
+                // The challenge target takes the same inputs.
+                for target in ["fuzz_compute_blob_kzg_proof", "fuzz_compute_challenge"] {
+                    let mut file = fuzz_corpus_file(target, index);
+                    file.write_all(&blob.bytes).unwrap();
+                    file.write_all(&commitment.bytes).unwrap();
                 }
             }
 
-            match kzg_settings.recover_cells_and_kzg_proofs(&cell_indices, &cells) {
-                Ok((recovered_cells, recovered_proofs)) => {
-                    let (expected_cells, expected_proofs) = test.get_output().unwrap();
-                    assert_eq!(recovered_cells.as_slice(), expected_cells);
-                    let proofs_as_bytes: Vec<Bytes48> =
-                        recovered_proofs.iter().map(|p| p.to_bytes()).collect();
-                    assert_eq!(proofs_as_bytes, expected_proofs);
-                }
-                _ => assert!(test.get_output().is_none()),
+            // The blob proof is the proof at the challenge.
+            if let Some(expected) = test.get_output().unwrap() {
+                let z = kzg_settings.compute_challenge(&blob, &commitment).unwrap();
+                let (proof, _) = kzg_settings.compute_kzg_proof(&blob, &z).unwrap();
+                assert_eq!(proof.bytes, expected.bytes);
             }
         }
     }
 
     #[test]
-    fn test_verify_cell_kzg_proof_batch() {
-        let trusted_setup_file = Path::new("src/trusted_setup.txt");
-        assert!(trusted_setup_file.exists());
-        let kzg_settings = KZGSettings::load_trusted_setup_file(trusted_setup_file, 0).unwrap();
-        let test_files: Vec<PathBuf> = glob::glob(VERIFY_CELL_KZG_PROOF_BATCH_TESTS)
-            .unwrap()
-            .map(Result::unwrap)
-            .collect();
-        assert!(!test_files.is_empty());
-
+    fn test_verify_kzg_proof() {
         #[allow(unused_variables)]
-        for (index, test_file) in test_files.iter().enumerate() {
-            let yaml_data = fs::read_to_string(test_file).unwrap();
-            let test: verify_cell_kzg_proof_batch::Test = serde_yaml::from_str(&yaml_data).unwrap();
-            let (Ok(commitments), Ok(cell_indices), Ok(cells), Ok(proofs)) = (
-                test.input.get_commitments(),
-                test.input.get_cell_indices(),
-                test.input.get_cells(),
-                test.input.get_proofs(),
-            ) else {
-                assert!(test.get_output().is_none());
-                continue;
-            };
+        for (index, yaml_data) in run_spec_test_cases(SpecTestHandler::VerifyKzgProof)
+            .iter()
+            .enumerate()
+        {
+            #[cfg(feature = "generate-fuzz-corpus")]
+            {
+                use std::io::Write;
+                let test: verify_kzg_proof::Test = serde_yaml::from_str(yaml_data).unwrap();
+                if let (Ok(commitment), Ok(z), Ok(y), Ok(proof)) = (
+                    test.input.get_commitment(),
+                    test.input.get_z(),
+                    test.input.get_y(),
+                    test.input.get_proof(),
+                ) {
+                    let mut file = fuzz_corpus_file("fuzz_verify_kzg_proof", index);
+                    file.write_all(&commitment.bytes).unwrap();
+                    file.write_all(&z.bytes).unwrap();
+                    file.write_all(&y.bytes).unwrap();
+                    file.write_all(&proof.bytes).unwrap();
+                }
+            }
+        }
+    }
 
+    #[test]
+    fn test_verify_blob_kzg_proof() {
+        #[allow(unused_variables)]
+        for (index, yaml_data) in run_spec_test_cases(SpecTestHandler::VerifyBlobKzgProof)
+            .iter()
+            .enumerate()
+        {
             #[cfg(feature = "generate-fuzz-corpus")]
             {
-                use std::{env, fs::File, io::Write};
-                let root_dir = PathBuf::from(env::var("CARGO_MANIFEST_DIR").unwrap());
-                let dir_path = root_dir
-                    .join("fuzz")
-                    .join("corpus")
-                    .join("fuzz_verify_cell_kzg_proof_batch");
-                fs::create_dir_all(&dir_path).unwrap();
-                let file_path = dir_path.join(format!("data_{index}.bin"));
-                let mut file = File::create(&file_path).unwrap();
-                for commitment in &commitments {
+                use std::io::Write;
+                let test: verify_blob_kzg_proof::Test = serde_yaml::from_str(yaml_data).unwrap();
+                if let (Ok(blob), Ok(commitment), Ok(proof)) = (
+                    test.input.get_blob(),
+                    test.input.get_commitment(),
+                    test.input.get_proof(),
+                ) {
+                    let mut file = fuzz_corpus_file("fuzz_verify_blob_kzg_proof", index);
+                    file.write_all(&blob.bytes).unwrap();
                     file.write_all(&commitment.bytes).unwrap();
+                    file.write_all(&proof.bytes).unwrap();
                 }
-                for cell_index in &cell_indices {
-                    file.write_all(&cell_index.to_le_bytes()).unwrap();
+            }
+        }
+    }
+
+    #[test]
+    fn test_verify_blob_kzg_proof_batch() {
+        #[allow(unused_variables)]
+        for (index, yaml_data) in run_spec_test_cases(SpecTestHandler::VerifyBlobKzgProofBatch)
+            .iter()
+            .enumerate()
+        {
+            #[cfg(feature = "generate-fuzz-corpus")]
+            {
+                use std::io::Write;
+                let test: verify_blob_kzg_proof_batch::Test =
+                    serde_yaml::from_str(yaml_data).unwrap();
+                if let (Ok(blobs), Ok(commitments), Ok(proofs)) = (
+                    test.input.get_blobs(),
+                    test.input.get_commitments(),
+                    test.input.get_proofs(),
+                ) {
+                    let mut file = fuzz_corpus_file("fuzz_verify_blob_kzg_proof_batch", index);
+                    for blob in &blobs {
+                        file.write_all(&blob.bytes).unwrap();
+                    }
+                    for commitment in &commitments {
+                        file.write_all(&commitment.bytes).unwrap();
+                    }
+                    for proof in &proofs {
+                        file.write_all(&proof.bytes).unwrap();
+                    }
                 }
-                for cell in &cells {
-                    file.write_all(&cell.bytes).unwrap();
+            }
+        }
+    }
+
+    #[test]
+    fn test_compute_cells() {
+        #[allow(unused_variables)]
+        for (index, yaml_data) in run_spec_test_cases(SpecTestHandler::ComputeCells)
+            .iter()
+            .enumerate()
+        {
+            #[cfg(feature = "generate-fuzz-corpus")]
+            {
+                use std::io::Write;
+                let test: compute_cells::Test = serde_yaml::from_str(yaml_data).unwrap();
+                if let Ok(blob) = test.input.get_blob() {
+                    let mut file = fuzz_corpus_file("fuzz_compute_cells", index);
+                    file.write_all(&blob.bytes).unwrap();
                 }
-                for proof in &proofs {
-                    file.write_all(&proof.bytes).unwrap();
+            }
+        }
+    }
+
+    #[test]
+    fn test_compute_cells_and_kzg_proofs() {
+        #[allow(unused_variables)]
+        for (index, yaml_data) in run_spec_test_cases(SpecTestHandler::ComputeCellsAndKzgProofs)
+            .iter()
+            .enumerate()
+        {
+            #[cfg(feature = "generate-fuzz-corpus")]
+            {
+                use std::io::Write;
+                let test: compute_cells_and_kzg_proofs::Test =
+                    serde_yaml::from_str(yaml_data).unwrap();
+                if let Ok(blob) = test.input.get_blob() {
+                    let mut file = fuzz_corpus_file("fuzz_compute_cells_and_kzg_proofs", index);
+                    file.write_all(&blob.bytes).unwrap();
                 }
             }
+        }
+    }
 
-            match kzg_settings.verify_cell_kzg_proof_batch(
-                &commitments,
-                &cell_indices,
-                &cells,
-                &proofs,
-            ) {
-                Ok(res) => assert_eq!(res, test.get_output().unwrap()),
-                _ => assert!(test.get_output().is_none()),
+    #[test]
+    fn test_recover_cells_and_kzg_proofs() {
+        #[allow(unused_variables)]
+        for (index, yaml_data) in run_spec_test_cases(SpecTestHandler::RecoverCellsAndKzgProofs)
+            .iter()
+            .enumerate()
+        {
+            #[cfg(feature = "generate-fuzz-corpus")]
+            {
+                use std::io::Write;
+                let test: recover_cells_and_kzg_proofs::Test =
+                    serde_yaml::from_str(yaml_data).unwrap();
+                if let (Ok(cell_indices), Ok(cells)) =
+                    (test.input.get_cell_indices(), test.input.get_cells())
+                {
+                    let mut file = fuzz_corpus_file("fuzz_recover_cells_and_kzg_proofs", index);
+                    for cell_index in &cell_indices {
+                        file.write_all(&cell_index.to_le_bytes()).unwrap();
+                    }
+                    for cell in &cells {
+                        file.write_all(&cell.bytes).unwrap();
+                    }
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn test_verify_cell_kzg_proof_batch() {
+        #[allow(unused_variables)]
+        for (index, yaml_data) in run_spec_test_cases(SpecTestHandler::VerifyCellKzgProofBatch)
+            .iter()
+            .enumerate()
+        {
+            #[cfg(feature = "generate-fuzz-corpus")]
+            {
+                use std::io::Write;
+                let test: verify_cell_kzg_proof_batch::Test =
+                    serde_yaml::from_str(yaml_data).unwrap();
+                if let (Ok(commitments), Ok(cell_indices), Ok(cells), Ok(proofs)) = (
+                    test.input.get_commitments(),
+                    test.input.get_cell_indices(),
+                    test.input.get_cells(),
+                    test.input.get_proofs(),
+                ) {
+                    let mut file = fuzz_corpus_file("fuzz_verify_cell_kzg_proof_batch", index);
+                    for commitment in &commitments {
+                        file.write_all(&commitment.bytes).unwrap();
+                    }
+                    for cell_index in &cell_indices {
+                        file.write_all(&cell_index.to_le_bytes()).unwrap();
+                    }
+                    for cell in &cells {
+                        file.write_all(&cell.bytes).unwrap();
+                    }
+                    for proof in &proofs {
+                        file.write_all(&proof.bytes).unwrap();
+                    }
+                }
             }
         }
     }
@@ -0,0 +1,450 @@
+//! RLP encoding and decoding of the blob transaction network wrapper.
+//!
+//! Pooled blob transactions are sent as `0x03 || rlp([tx_payload_body, blobs, commitments,
+//! proofs])`, or with EIP-7594 as `0x03 || rlp([tx_payload_body, wrapper_version, blobs,
+//! commitments, cell_proofs])`. The functions here handle the RLP list, without the transaction
+//! type byte. The transaction payload is kept as its raw RLP encoding.
+
+use crate::{
+    Blob, BlobTransactionSidecar, BlobTransactionSidecarV1, Bytes48, Error, BYTES_PER_BLOB,
+    BYTES_PER_COMMITMENT, BYTES_PER_PROOF, CELLS_PER_EXT_BLOB,
+};
+use alloc::vec::Vec;
+
+/// The wrapper version of the network wrapper with cell proofs.
+pub const NETWORK_WRAPPER_VERSION_CELL_PROOFS: u8 = 1;
+
+const STRING_OFFSET: u8 = 0x80;
+const LIST_OFFSET: u8 = 0xc0;
+/// Payloads shorter than this have their length in the first byte.
+const SHORT_PAYLOAD_LIMIT: usize = 56;
+
+fn header_len(payload_len: usize) -> usize {
+    if payload_len < SHORT_PAYLOAD_LIMIT {
+        1
+    } else {
+        1 + length_of_length(payload_len)
+    }
+}
+
+fn length_of_length(payload_len: usize) -> usize {
+    (usize::BITS - payload_len.leading_zeros()).div_ceil(8) as usize
+}
+
+fn encode_header(out: &mut Vec<u8>, offset: u8, payload_len: usize) {
+    if payload_len < SHORT_PAYLOAD_LIMIT {
+        out.push(offset + payload_len as u8);
+    } else {
+        let len_bytes = payload_len.to_be_bytes();
+        let len_of_len = length_of_length(payload_len);
+        out.push(offset + SHORT_PAYLOAD_LIMIT as u8 - 1 + len_of_len as u8);
+        out.extend_from_slice(&len_bytes[len_bytes.len() - len_of_len..]);
+    }
+}
+
+/// The length of the encoding of a list of byte strings which are all `item_len` long.
+fn fixed_list_len(count: usize, item_len: usize) -> usize {
+    let payload_len = count * (header_len(item_len) + item_len);
+    header_len(payload_len) + payload_len
+}
+
+/// Appends a list of byte strings which are all `item_len` long, which must be more than one.
+fn encode_fixed_list<'a, I>(out: &mut Vec<u8>, items: I, count: usize, item_len: usize)
+where
+    I: Iterator<Item = &'a [u8]>,
+{
+    debug_assert!(item_len > 1);
+    encode_header(out, LIST_OFFSET, count * (header_len(item_len) + item_len));
+    for item in items {
+        encode_header(out, STRING_OFFSET, item_len);
+        out.extend_from_slice(item);
+    }
+}
+
+fn invalid(message: &str) -> Error {
+    Error::InvalidRlp(message.into())
+}
+
+/// Decodes an item header, returning whether it is a list and the payload. The buffer is
+/// advanced past the item.
+fn decode_item<'a>(buf: &mut &'a [u8]) -> Result<(bool, &'a [u8]), Error> {
+    let (&first, rest) = buf
+        .split_first()
+        .ok_or_else(|| invalid("Unexpected end of input"))?;
+    let (is_list, offset) = match first {
+        0x00..=0x7f => {
+            let (payload, rest) = buf.split_at(1);
+            *buf = rest;
+            return Ok((false, payload));
+        }
+        0x80..=0xbf => (false, STRING_OFFSET),
+        0xc0..=0xff => (true, LIST_OFFSET),
+    };
+    let short_len = (first - offset) as usize;
+    let (payload_len, rest) = if short_len < SHORT_PAYLOAD_LIMIT {
+        (short_len, rest)
+    } else {
+        let len_of_len = short_len - (SHORT_PAYLOAD_LIMIT - 1);
+        if rest.len() < len_of_len {
+            return Err(invalid("Unexpected end of input"));
+        }
+        let (len_bytes, rest) = rest.split_at(len_of_len);
+        if len_bytes[0] == 0 || len_of_len > core::mem::size_of::<usize>() {
+            return Err(invalid("Non-canonical length"));
+        }
+        let payload_len = len_bytes
+            .iter()
+            .fold(0usize, |len, &byte| (len << 8) | byte as usize);
+        if payload_len < SHORT_PAYLOAD_LIMIT {
+            return Err(invalid("Non-canonical length"));
+        }
+        (payload_len, rest)
+    };
+    if rest.len() < payload_len {
+        return Err(invalid("Unexpected end of input"));
+    }
+    let (payload, rest) = rest.split_at(payload_len);
+    if !is_list && payload_len == 1 && payload[0] < STRING_OFFSET {
+        return Err(invalid("Non-canonical single byte"));
+    }
+    *buf = rest;
+    Ok((is_list, payload))
+}
+
+fn decode_list<'a>(buf: &mut &'a [u8]) -> Result<&'a [u8], Error> {
+    match decode_item(buf)? {
+        (true, payload) => Ok(payload),
+        (false, _) => Err(invalid("Expected a list")),
+    }
+}
+
+/// Decodes a list of byte strings which are all exactly `item_len` long, calling `f` with each.
+fn decode_fixed_list<'a>(
+    buf: &mut &'a [u8],
+    item_len: usize,
+    mut f: impl FnMut(&'a [u8]),
+) -> Result<usize, Error> {
+    let mut payload = decode_list(buf)?;
+    let encoded_len = header_len(item_len) + item_len;
+    if payload.len() % encoded_len != 0 {
+        return Err(Error::InvalidBytesLength(format!(
+            "List items are not all {item_len} bytes long"
+        )));
+    }
+    let count = payload.len() / encoded_len;
+    while !payload.is_empty() {
+        let item = match decode_item(&mut payload)? {
+            (false, item) if item.len() == item_len => item,
+            _ => {
+                return Err(Error::InvalidBytesLength(format!(
+                    "List items are not all {item_len} bytes long"
+                )))
+            }
+        };
+        f(item);
+    }
+    Ok(count)
+}
+
+fn decode_blobs(buf: &mut &[u8]) -> Result<Vec<Blob>, Error> {
+    let mut items = Vec::new();
+    decode_fixed_list(buf, BYTES_PER_BLOB, |item| items.push(item))?;
+    // Copy each blob straight from the input into its final place.
+    let mut blobs = Vec::with_capacity(items.len());
+    blobs.resize_with(items.len(), Blob::default);
+    for (blob, item) in blobs.iter_mut().zip(items) {
+        blob.bytes.copy_from_slice(item);
+    }
+    Ok(blobs)
+}
+
+fn decode_bytes48_list(buf: &mut &[u8], item_len: usize) -> Result<Vec<Bytes48>, Error> {
+    let mut values = Vec::new();
+    decode_fixed_list(buf, item_len, |item| {
+        let mut value = Bytes48::default();
+        value.bytes.copy_from_slice(item);
+        values.push(value);
+    })?;
+    Ok(values)
+}
+
+fn encode_blobs(out: &mut Vec<u8>, blobs: &[Blob]) {
+    let items = blobs.iter().map(|blob| &blob.bytes[..]);
+    encode_fixed_list(out, items, blobs.len(), BYTES_PER_BLOB);
+}
+
+fn encode_bytes48_list(out: &mut Vec<u8>, values: &[Bytes48], item_len: usize) {
+    let items = values.iter().map(|value| &value.bytes[..]);
+    encode_fixed_list(out, items, values.len(), item_len);
+}
+
+/// Returns the transaction payload, which must be an RLP list, including its header.
+fn decode_tx_payload<'a>(buf: &mut &'a [u8]) -> Result<&'a [u8], Error> {
+    let start = *buf;
+    decode_list(buf)?;
+    Ok(&start[..start.len() - buf.len()])
+}
+
+fn check_no_trailing_bytes(buf: &[u8]) -> Result<(), Error> {
+    if !buf.is_empty() {
+        return Err(invalid("Unexpected trailing bytes"));
+    }
+    Ok(())
+}
+
+impl BlobTransactionSidecar {
+    /// Returns the length of [`Self::rlp_encode_fields`].
+    pub fn rlp_fields_len(&self) -> usize {
+        fixed_list_len(self.blobs.len(), BYTES_PER_BLOB)
+            + fixed_list_len(self.commitments.len(), BYTES_PER_COMMITMENT)
+            + fixed_list_len(self.proofs.len(), BYTES_PER_PROOF)
+    }
+
+    /// Appends the RLP encoding of the blobs, commitments and proofs lists, as they appear in the
+    /// network wrapper.
+    pub fn rlp_encode_fields(&self, out: &mut Vec<u8>) {
+        encode_blobs(out, &self.blobs);
+        encode_bytes48_list(out, &self.commitments, BYTES_PER_COMMITMENT);
+        encode_bytes48_list(out, &self.proofs, BYTES_PER_PROOF);
+    }
+
+    /// Decodes the blobs, commitments and proofs lists, advancing the buffer past them.
+    ///
+    /// Every blob, commitment and proof must have the exact length, and there must be as many
+    /// commitments and proofs as blobs.
+    pub fn rlp_decode_fields(buf: &mut &[u8]) -> Result<Self, Error> {
+        let sidecar = Self {
+            blobs: decode_blobs(buf)?,
+            commitments: decode_bytes48_list(buf, BYTES_PER_COMMITMENT)?,
+            proofs: decode_bytes48_list(buf, BYTES_PER_PROOF)?,
+        };
+        if sidecar.blobs.len() != sidecar.commitments.len()
+            || sidecar.blobs.len() != sidecar.proofs.len()
+        {
+            return Err(Error::MismatchLength(format!(
+                "There are {} blobs, {} commitments and {} proofs",
+                sidecar.blobs.len(),
+                sidecar.commitments.len(),
+                sidecar.proofs.len()
+            )));
+        }
+        Ok(sidecar)
+    }
+}
+
+impl BlobTransactionSidecarV1 {
+    /// Returns the length of [`Self::rlp_encode_fields`].
+    pub fn rlp_fields_len(&self) -> usize {
+        1 + fixed_list_len(self.blobs.len(), BYTES_PER_BLOB)
+            + fixed_list_len(self.commitments.len(), BYTES_PER_COMMITMENT)
+            + fixed_list_len(self.cell_proofs.len(), BYTES_PER_PROOF)
+    }
+
+    /// Appends the RLP encoding of the wrapper version and the blobs, commitments and cell
+    /// proofs lists, as they appear in the network wrapper.
+    pub fn rlp_encode_fields(&self, out: &mut Vec<u8>) {
+        out.push(NETWORK_WRAPPER_VERSION_CELL_PROOFS);
+        encode_blobs(out, &self.blobs);
+        encode_bytes48_list(out, &self.commitments, BYTES_PER_COMMITMENT);
+        encode_bytes48_list(out, &self.cell_proofs, BYTES_PER_PROOF);
+    }
+
+    /// Decodes the wrapper version and the blobs, commitments and cell proofs lists, advancing
+    /// the buffer past them.
+    ///
+    /// Every blob, commitment and proof must have the exact length, and there must be a
+    /// commitment and [`CELLS_PER_EXT_BLOB`] cell proofs per blob.
+    pub fn rlp_decode_fields(buf: &mut &[u8]) -> Result<Self, Error> {
+        match decode_item(buf)? {
+            (false, [NETWORK_WRAPPER_VERSION_CELL_PROOFS]) => {}
+            _ => return Err(invalid("Unsupported wrapper version")),
+        }
+        let sidecar = Self {
+            blobs: decode_blobs(buf)?,
+            commitments: decode_bytes48_list(buf, BYTES_PER_COMMITMENT)?,
+            cell_proofs: decode_bytes48_list(buf, BYTES_PER_PROOF)?,
+        };
+        if sidecar.blobs.len() != sidecar.commitments.len()
+            || sidecar.blobs.len() * CELLS_PER_EXT_BLOB != sidecar.cell_proofs.len()
+        {
+            return Err(Error::MismatchLength(format!(
+                "There are {} blobs, {} commitments and {} cell proofs",
+                sidecar.blobs.len(),
+                sidecar.commitments.len(),
+                sidecar.cell_proofs.len()
+            )));
+        }
+        Ok(sidecar)
+    }
+}
+
+/// Encodes `rlp([tx_payload_body, blobs, commitments, proofs])`.
+///
+/// `tx_payload_body` is the RLP encoding of the transaction payload list.
+pub fn encode_network_wrapper(tx_payload_body: &[u8], sidecar: &BlobTransactionSidecar) -> Vec<u8> {
+    let payload_len = tx_payload_body.len() + sidecar.rlp_fields_len();
+    let mut out = Vec::with_capacity(header_len(payload_len) + payload_len);
+    encode_header(&mut out, LIST_OFFSET, payload_len);
+    out.extend_from_slice(tx_payload_body);
+    sidecar.rlp_encode_fields(&mut out);
+    out
+}
+
+/// Decodes `rlp([tx_payload_body, blobs, commitments, proofs])`, returning the RLP encoding of
+/// the transaction payload list and the sidecar.
+pub fn decode_network_wrapper(bytes: &[u8]) -> Result<(&[u8], BlobTransactionSidecar), Error> {
+    let mut buf = bytes;
+    let mut fields = decode_list(&mut buf)?;
+    check_no_trailing_bytes(buf)?;
+    let tx_payload_body = decode_tx_payload(&mut fields)?;
+    let sidecar = BlobTransactionSidecar::rlp_decode_fields(&mut fields)?;
+    check_no_trailing_bytes(fields)?;
+    Ok((tx_payload_body, sidecar))
+}
+
+/// Encodes `rlp([tx_payload_body, wrapper_version, blobs, commitments, cell_proofs])`.
+///
+/// `tx_payload_body` is the RLP encoding of the transaction payload list.
+pub fn encode_network_wrapper_v1(
+    tx_payload_body: &[u8],
+    sidecar: &BlobTransactionSidecarV1,
+) -> Vec<u8> {
+    let payload_len = tx_payload_body.len() + sidecar.rlp_fields_len();
+    let mut out = Vec::with_capacity(header_len(payload_len) + payload_len);
+    encode_header(&mut out, LIST_OFFSET, payload_len);
+    out.extend_from_slice(tx_payload_body);
+    sidecar.rlp_encode_fields(&mut out);
+    out
+}
+
+/// Decodes `rlp([tx_payload_body, wrapper_version, blobs, commitments, cell_proofs])`,
+/// returning the RLP encoding of the transaction payload list and the sidecar.
+pub fn decode_network_wrapper_v1(bytes: &[u8]) -> Result<(&[u8], BlobTransactionSidecarV1), Error> {
+    let mut buf = bytes;
+    let mut fields = decode_list(&mut buf)?;
+    check_no_trailing_bytes(buf)?;
+    let tx_payload_body = decode_tx_payload(&mut fields)?;
+    let sidecar = BlobTransactionSidecarV1::rlp_decode_fields(&mut fields)?;
+    check_no_trailing_bytes(fields)?;
+    Ok((tx_payload_body, sidecar))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rand::Rng;
+
+    /// `rlp([1, 2])`, standing in for the transaction payload.
+    const TX_PAYLOAD_BODY: [u8; 3] = [0xc2, 0x01, 0x02];
+
+    fn random_sidecar(num_blobs: usize) -> BlobTransactionSidecar {
+        let mut rng = rand::rng();
+        let mut blobs = Vec::new();
+        blobs.resize_with(num_blobs, Blob::default);
+        for blob in &mut blobs {
+            rng.fill(&mut blob.bytes[..]);
+        }
+        BlobTransactionSidecar {
+            blobs,
+            commitments: (0..num_blobs)
+                .map(|_| Bytes48::from(rng.random::<[u8; 48]>()))
+                .collect(),
+            proofs: (0..num_blobs)
+                .map(|_| Bytes48::from(rng.random::<[u8; 48]>()))
+                .collect(),
+        }
+    }
+
+    #[test]
+    fn test_network_wrapper_roundtrip() {
+        let sidecar = random_sidecar(2);
+        let encoded = encode_network_wrapper(&TX_PAYLOAD_BODY, &sidecar);
+        assert_eq!(
+            encoded.len(),
+            4 + TX_PAYLOAD_BODY.len() + sidecar.rlp_fields_len()
+        );
+
+        // A long list header, then the transaction and the blobs list with two long strings.
+        assert_eq!(encoded[0], 0xfa);
+        assert_eq!(&encoded[4..7], &TX_PAYLOAD_BODY);
+        assert_eq!(&encoded[7..11], &[0xfa, 0x04, 0x00, 0x08]);
+        assert_eq!(&encoded[11..15], &[0xba, 0x02, 0x00, 0x00]);
+        // Two commitments of one header byte and 48 bytes each.
+        let commitments_start = 7 + fixed_list_len(2, BYTES_PER_BLOB);
+        assert_eq!(
+            &encoded[commitments_start..commitments_start + 3],
+            &[0xf8, 0x62, 0xb0]
+        );
+
+        let (tx_payload_body, decoded) = decode_network_wrapper(&encoded).unwrap();
+        assert_eq!(tx_payload_body, &TX_PAYLOAD_BODY);
+        assert_eq!(decoded, sidecar);
+
+        let empty = random_sidecar(0);
+        let encoded = encode_network_wrapper(&TX_PAYLOAD_BODY, &empty);
+        assert_eq!(encoded, [0xc6, 0xc2, 0x01, 0x02, 0xc0, 0xc0, 0xc0]);
+        assert_eq!(decode_network_wrapper(&encoded).unwrap().1, empty);
+    }
+
+    #[test]
+    fn test_network_wrapper_v1_roundtrip() {
+        let sidecar = random_sidecar(1);
+        let sidecar = BlobTransactionSidecarV1 {
+            blobs: sidecar.blobs,
+            commitments: sidecar.commitments,
+            cell_proofs: vec![Bytes48::from([7; 48]); CELLS_PER_EXT_BLOB],
+        };
+        let encoded = encode_network_wrapper_v1(&TX_PAYLOAD_BODY, &sidecar);
+        assert_eq!(encoded[4 + TX_PAYLOAD_BODY.len()], 0x01);
+        let (tx_payload_body, decoded) = decode_network_wrapper_v1(&encoded).unwrap();
+        assert_eq!(tx_payload_body, &TX_PAYLOAD_BODY);
+        assert_eq!(decoded, sidecar);
+
+        // The version 0 decoder does not accept it.
+        assert!(decode_network_wrapper(&encoded).is_err());
+    }
+
+    #[test]
+    fn test_network_wrapper_invalid() {
+        let sidecar = random_sidecar(1);
+        let encoded = encode_network_wrapper(&TX_PAYLOAD_BODY, &sidecar);
+
+        // Truncated, or with trailing bytes.
+        assert!(decode_network_wrapper(&encoded[..encoded.len() - 1]).is_err());
+        let mut trailing = encoded.clone();
+        trailing.push(0);
+        assert!(decode_network_wrapper(&trailing).is_err());
+
+        // A commitment which is one byte short.
+        let mut short = random_sidecar(1);
+        short.commitments.clear();
+        let mut fields = Vec::new();
+        encode_blobs(&mut fields, &short.blobs);
+        encode_header(&mut fields, LIST_OFFSET, 48);
+        encode_header(&mut fields, STRING_OFFSET, 47);
+        fields.extend_from_slice(&[0; 47]);
+        encode_bytes48_list(&mut fields, &short.proofs, BYTES_PER_PROOF);
+        let mut buf = &fields[..];
+        assert!(matches!(
+            BlobTransactionSidecar::rlp_decode_fields(&mut buf),
+            Err(Error::InvalidBytesLength(_))
+        ));
+
+        // A proof missing.
+        let mut missing = sidecar.clone();
+        missing.proofs.clear();
+        let encoded = encode_network_wrapper(&TX_PAYLOAD_BODY, &missing);
+        assert!(matches!(
+            decode_network_wrapper(&encoded),
+            Err(Error::MismatchLength(_))
+        ));
+
+        // A transaction payload which is not a list.
+        let encoded = encode_network_wrapper(&[0x80], &sidecar);
+        assert!(decode_network_wrapper(&encoded).is_err());
+
+        // A length with a leading zero byte.
+        assert!(decode_network_wrapper(&[0xf8, 0x00]).is_err());
+        assert!(decode_network_wrapper(&[0xf8, 0x01, 0xc0]).is_err());
+    }
+}
@@ -1,17 +1,33 @@
 //! Serde serialization and deserialization for the basic types in this crate.
+//!
+//! Human-readable formats such as JSON use 0x-prefixed hex strings, binary formats such as
+//! bincode or CBOR use the raw bytes.
 
-use crate::{Blob, Bytes32, Bytes48};
+use super::{
+    BYTES_PER_BLOB, BYTES_PER_CELL, BYTES_PER_COMMITMENT, BYTES_PER_PROOF, CELLS_PER_EXT_BLOB,
+};
+use crate::{
+    Blob, Bytes32, Bytes48, Cell, KzgCommitment, KzgProof, ValidatedKzgCommitment,
+    ValidatedKzgProof,
+};
+use alloc::boxed::Box;
 use alloc::string::String;
 use alloc::vec::Vec;
+use core::fmt;
+use serde::de::{SeqAccess, Visitor};
 use serde::{de::Error, Deserialize, Deserializer, Serialize, Serializer};
 
-/// Serialize a byte vec as a hex string with 0x prefix
+/// Serialize a byte vec as a hex string with 0x prefix, or as raw bytes for binary formats.
 pub fn serialize_bytes<S, T>(x: T, s: S) -> Result<S::Ok, S::Error>
 where
     S: Serializer,
     T: AsRef<[u8]>,
 {
-    s.serialize_str(&format!("0x{}", hex::encode(x.as_ref())))
+    if s.is_human_readable() {
+        s.serialize_str(&format!("0x{}", hex::encode(x.as_ref())))
+    } else {
+        s.serialize_bytes(x.as_ref())
+    }
 }
 
 fn deserialize_hex<'de, D: Deserializer<'de>>(deserializer: D) -> Result<Vec<u8>, D::Error> {
@@ -20,6 +36,55 @@ fn deserialize_hex<'de, D: Deserializer<'de>>(deserializer: D) -> Result<Vec<u8>
     hex::decode(hex_bytes).map_err(Error::custom)
 }
 
+/// Accepts raw bytes, or a sequence of bytes for formats without a native bytes type.
+///
+/// A sequence longer than `len` is rejected as soon as the extra byte is read, and its size hint
+/// is not trusted beyond `len`, so a short input cannot force a large allocation.
+struct BytesVisitor {
+    len: usize,
+}
+
+impl<'de> Visitor<'de> for BytesVisitor {
+    type Value = Vec<u8>;
+
+    fn expecting(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+        write!(formatter, "a byte array of length {}", self.len)
+    }
+
+    fn visit_bytes<E: Error>(self, v: &[u8]) -> Result<Self::Value, E> {
+        Ok(v.to_vec())
+    }
+
+    fn visit_byte_buf<E: Error>(self, v: Vec<u8>) -> Result<Self::Value, E> {
+        Ok(v)
+    }
+
+    fn visit_seq<A: SeqAccess<'de>>(self, mut seq: A) -> Result<Self::Value, A::Error> {
+        let mut bytes = Vec::with_capacity(seq.size_hint().unwrap_or(0).min(self.len));
+        while let Some(byte) = seq.next_element()? {
+            if bytes.len() == self.len {
+                return Err(Error::invalid_length(bytes.len() + 1, &self));
+            }
+            bytes.push(byte);
+        }
+        Ok(bytes)
+    }
+}
+
+/// Deserialize a hex string for human-readable formats, or raw bytes for binary formats.
+///
+/// `len` is the expected number of bytes, which bounds what is read from binary formats.
+fn deserialize_bytes<'de, D: Deserializer<'de>>(
+    deserializer: D,
+    len: usize,
+) -> Result<Vec<u8>, D::Error> {
+    if deserializer.is_human_readable() {
+        deserialize_hex(deserializer)
+    } else {
+        deserializer.deserialize_byte_buf(BytesVisitor { len })
+    }
+}
+
 impl Serialize for Blob {
     fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
     where
@@ -31,7 +96,7 @@ impl Serialize for Blob {
 
 impl<'de> Deserialize<'de> for Blob {
     fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
-        Blob::from_bytes(&deserialize_hex(deserializer)?).map_err(Error::custom)
+        Blob::from_bytes(&deserialize_bytes(deserializer, BYTES_PER_BLOB)?).map_err(Error::custom)
     }
 }
 
@@ -43,7 +108,7 @@ impl Serialize for Bytes48 {
 
 impl<'de> Deserialize<'de> for Bytes48 {
     fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
-        Bytes48::from_bytes(&deserialize_hex(deserializer)?).map_err(Error::custom)
+        Bytes48::from_bytes(&deserialize_bytes(deserializer, 48)?).map_err(Error::custom)
     }
 }
 
@@ -61,13 +126,152 @@ impl<'de> Deserialize<'de> for Bytes32 {
     where
         D: Deserializer<'de>,
     {
-        Bytes32::from_bytes(&deserialize_hex(deserializer)?).map_err(Error::custom)
+        Bytes32::from_bytes(&deserialize_bytes(deserializer, 32)?).map_err(Error::custom)
+    }
+}
+
+impl Serialize for Cell {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serialize_bytes(self.bytes, serializer)
+    }
+}
+
+impl<'de> Deserialize<'de> for Cell {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        Cell::from_bytes(&deserialize_bytes(deserializer, BYTES_PER_CELL)?).map_err(Error::custom)
+    }
+}
+
+impl Serialize for KzgCommitment {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serialize_bytes(self.bytes, serializer)
+    }
+}
+
+impl<'de> Deserialize<'de> for KzgCommitment {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        KzgCommitment::from_bytes(&deserialize_bytes(deserializer, BYTES_PER_COMMITMENT)?)
+            .map_err(Error::custom)
+    }
+}
+
+impl Serialize for KzgProof {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serialize_bytes(self.bytes, serializer)
+    }
+}
+
+impl<'de> Deserialize<'de> for KzgProof {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        KzgProof::from_bytes(&deserialize_bytes(deserializer, BYTES_PER_PROOF)?)
+            .map_err(Error::custom)
+    }
+}
+
+impl Serialize for ValidatedKzgCommitment {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        self.bytes.serialize(serializer)
+    }
+}
+
+/// Deserializing checks that the commitment is a valid G1 point.
+impl<'de> Deserialize<'de> for ValidatedKzgCommitment {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        ValidatedKzgCommitment::new(&Bytes48::deserialize(deserializer)?).map_err(Error::custom)
+    }
+}
+
+impl Serialize for ValidatedKzgProof {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        self.bytes.serialize(serializer)
+    }
+}
+
+/// Deserializing checks that the proof is a valid G1 point.
+impl<'de> Deserialize<'de> for ValidatedKzgProof {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        ValidatedKzgProof::new(&Bytes48::deserialize(deserializer)?).map_err(Error::custom)
+    }
+}
+
+/// Serde helpers for boxed arrays with one entry per cell of an extended blob, such as
+/// [`CellsPerExtBlob`](super::CellsPerExtBlob) and [`ProofsPerExtBlob`](super::ProofsPerExtBlob).
+///
+/// Use with `#[serde(with = "c_kzg::ext_blob_array")]`.
+pub mod ext_blob_array {
+    use super::*;
+
+    #[allow(clippy::borrowed_box)]
+    pub fn serialize<S, T>(items: &Box<[T; CELLS_PER_EXT_BLOB]>, s: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+        T: Serialize,
+    {
+        s.collect_seq(items.iter())
+    }
+
+    pub fn deserialize<'de, D, T>(deserializer: D) -> Result<Box<[T; CELLS_PER_EXT_BLOB]>, D::Error>
+    where
+        D: Deserializer<'de>,
+        T: Deserialize<'de>,
+    {
+        let items = Vec::<T>::deserialize(deserializer)?;
+        let len = items.len();
+        items
+            .into_boxed_slice()
+            .try_into()
+            .map_err(|_| Error::invalid_length(len, &"an entry for each cell of an extended blob"))
+    }
+}
+
+/// Same as [`ext_blob_array`], for optional boxed arrays.
+///
+/// Use with `#[serde(with = "c_kzg::option_ext_blob_array")]`.
+pub mod option_ext_blob_array {
+    use super::*;
+
+    struct Ref<'a, T>(&'a [T; CELLS_PER_EXT_BLOB]);
+
+    impl<T: Serialize> Serialize for Ref<'_, T> {
+        fn serialize<S: Serializer>(&self, s: S) -> Result<S::Ok, S::Error> {
+            s.collect_seq(self.0.iter())
+        }
+    }
+
+    struct Owned<T>(Box<[T; CELLS_PER_EXT_BLOB]>);
+
+    impl<'de, T: Deserialize<'de>> Deserialize<'de> for Owned<T> {
+        fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+            ext_blob_array::deserialize(deserializer).map(Owned)
+        }
+    }
+
+    pub fn serialize<S, T>(
+        items: &Option<Box<[T; CELLS_PER_EXT_BLOB]>>,
+        s: S,
+    ) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+        T: Serialize,
+    {
+        items.as_deref().map(Ref).serialize(s)
+    }
+
+    pub fn deserialize<'de, D, T>(
+        deserializer: D,
+    ) -> Result<Option<Box<[T; CELLS_PER_EXT_BLOB]>>, D::Error>
+    where
+        D: Deserializer<'de>,
+        T: Deserialize<'de>,
+    {
+        Ok(Option::<Owned<T>>::deserialize(deserializer)?.map(|items| items.0))
     }
 }
 
 #[cfg(test)]
 mod tests {
     use super::super::*;
+    use ::serde::{Deserialize, Serialize};
     use rand::{rngs::ThreadRng, Rng};
 
     fn generate_random_blob(rng: &mut ThreadRng) -> Blob {
@@ -82,7 +286,7 @@ mod tests {
     }
 
     fn trusted_setup_file() -> &'static Path {
-        Path::new("../../src/trusted_setup.txt")
+        Path::new("src/trusted_setup.txt")
     }
 
     #[test]
@@ -117,6 +321,147 @@ mod tests {
         assert_eq!(proof.to_bytes(), proof_deserialized);
     }
 
+    fn bincode_roundtrip<T>(value: &T) -> T
+    where
+        T: Serialize + for<'de> Deserialize<'de>,
+    {
+        bincode::deserialize(&bincode::serialize(value).unwrap()).unwrap()
+    }
+
+    fn cbor_roundtrip<T>(value: &T) -> T
+    where
+        T: Serialize + for<'de> Deserialize<'de>,
+    {
+        let mut bytes = Vec::new();
+        ciborium::into_writer(value, &mut bytes).unwrap();
+        ciborium::from_reader(bytes.as_slice()).unwrap()
+    }
+
+    #[test]
+    fn test_serialize_all_types_roundtrip() {
+        let trusted_setup_file = trusted_setup_file();
+        assert!(trusted_setup_file.exists());
+        let kzg_settings = KZGSettings::load_trusted_setup_file(trusted_setup_file, 0).unwrap();
+
+        let mut rng = rand::rng();
+        let blob = generate_random_blob(&mut rng);
+        let commitment = kzg_settings.blob_to_kzg_commitment(&blob).unwrap();
+        let proof = kzg_settings
+            .compute_blob_kzg_proof(&blob, &commitment.to_bytes())
+            .unwrap();
+        let extended_blob = kzg_settings.compute_extended_blob(&blob).unwrap();
+        let column = kzg_settings
+            .compute_data_columns(core::slice::from_ref(&blob))
+            .unwrap()
+            .remove(5);
+
+        // Human-readable formats use hex.
+        let json = serde_json::to_string(&column.cells[0]).unwrap();
+        assert_eq!(json.len(), 2 + 2 + 2 * BYTES_PER_CELL);
+        assert!(json.starts_with("\"0x"));
+        let json = serde_json::to_string(&commitment).unwrap();
+        assert_eq!(json, format!("\"0x{}\"", commitment.as_hex_string()));
+
+        // Binary formats use the raw bytes.
+        let bytes = bincode::serialize(&proof).unwrap();
+        assert_eq!(&bytes[bytes.len() - BYTES_PER_PROOF..], &proof.bytes[..]);
+        assert!(bincode::serialize(&blob).unwrap().len() < BYTES_PER_BLOB + 16);
+
+        for encoded in [
+            serde_json::from_str(&serde_json::to_string(&extended_blob).unwrap()).unwrap(),
+            bincode_roundtrip(&extended_blob),
+            cbor_roundtrip(&extended_blob),
+        ] {
+            let decoded: ExtendedBlob = encoded;
+            assert_eq!(decoded, extended_blob);
+        }
+        assert_eq!(cbor_roundtrip(&blob), blob);
+        assert_eq!(bincode_roundtrip(&column.cells[0]), column.cells[0]);
+        assert_eq!(cbor_roundtrip(&commitment).bytes, commitment.bytes);
+        assert_eq!(bincode_roundtrip(&proof).bytes, proof.bytes);
+
+        let decoded = bincode_roundtrip(&column);
+        assert_eq!(decoded.index, column.index);
+        assert_eq!(decoded.cells, column.cells);
+        assert_eq!(decoded.proofs[0].bytes, column.proofs[0].bytes);
+        assert_eq!(decoded.commitments[0].bytes, commitment.bytes);
+
+        let validated = ValidatedKZGCommitment::try_from(commitment).unwrap();
+        assert_eq!(cbor_roundtrip(&validated), validated);
+        let validated = ValidatedKZGProof::try_from(proof).unwrap();
+        assert_eq!(bincode_roundtrip(&validated), validated);
+
+        let recovery = VerifiedRecovery {
+            rejected: vec![1, 2],
+            cells: Some(extended_blob.clone().into_cells()),
+            proofs: None,
+        };
+        let json = serde_json::to_string(&recovery).unwrap();
+        let decoded: VerifiedRecovery = serde_json::from_str(&json).unwrap();
+        assert_eq!(decoded.rejected, recovery.rejected);
+        assert_eq!(decoded.cells, recovery.cells);
+        assert!(decoded.proofs.is_none());
+
+        let sidecar = BlobTransactionSidecar {
+            blobs: vec![blob.clone()],
+            commitments: vec![commitment.to_bytes()],
+            proofs: vec![proof.to_bytes()],
+        };
+        assert_eq!(cbor_roundtrip(&sidecar), sidecar);
+
+        // Invalid points and wrong lengths are rejected.
+        let invalid: Result<ValidatedKZGCommitment, _> =
+            serde_json::from_str(&format!("\"0x{}\"", "ff".repeat(BYTES_PER_COMMITMENT)));
+        assert!(invalid.is_err());
+        let short = bincode::serialize(&[0u8; 100][..]).unwrap();
+        assert!(bincode::deserialize::<Cell>(&short).is_err());
+        assert!(bincode::deserialize::<ExtendedBlob>(
+            &bincode::serialize(&vec![column.cells[0]; 3]).unwrap()
+        )
+        .is_err());
+    }
+
+    #[test]
+    fn test_deserialize_bytes_from_seq() {
+        // CBOR arrays of integers are read as a sequence of bytes.
+        let mut bytes = Vec::new();
+        ciborium::into_writer(&[7u8; 32].to_vec(), &mut bytes).unwrap();
+        let decoded: Bytes32 = ciborium::from_reader(bytes.as_slice()).unwrap();
+        assert_eq!(decoded, Bytes32::new([7; 32]));
+
+        // One byte too many is rejected.
+        let mut bytes = Vec::new();
+        ciborium::into_writer(&[7u8; 33].to_vec(), &mut bytes).unwrap();
+        assert!(ciborium::from_reader::<Bytes32, _>(bytes.as_slice()).is_err());
+
+        // An array which claims about 4 GiB of elements is rejected without allocating them.
+        let huge = [0x9a, 0xff, 0xff, 0xff, 0xff, 0x00, 0x01];
+        assert!(ciborium::from_reader::<Blob, _>(&huge[..]).is_err());
+    }
+
+    #[test]
+    fn test_blobs_bundle_json() {
+        let mut rng = rand::rng();
+        let blob = generate_random_blob(&mut rng);
+        let commitment = Bytes48::from([0xc0; 48]);
+        let proof = Bytes48::from([0x11; 48]);
+        let json = format!(
+            r#"{{"commitments":["0x{}"],"proofs":["0x{}"],"blobs":["0x{}"]}}"#,
+            hex::encode(commitment.bytes),
+            hex::encode(proof.bytes),
+            hex::encode(blob.bytes)
+        );
+
+        let bundle: BlobsBundleV1 = serde_json::from_str(&json).unwrap();
+        assert_eq!(bundle.commitments, vec![commitment]);
+        assert_eq!(bundle.proofs, vec![proof]);
+        assert_eq!(bundle.blobs, vec![blob]);
+        assert_eq!(serde_json::to_string(&bundle).unwrap(), json);
+
+        let bundle: BlobsBundleV2 = serde_json::from_str(&json).unwrap();
+        assert_eq!(serde_json::to_string(&bundle).unwrap(), json);
+    }
+
     #[test]
     fn test_serialize_blob_with_prefix() {
         // generate blob
@@ -0,0 +1,315 @@
+//! A runner for the consensus-spec KZG test vectors.
+//!
+//! The vectors are laid out as `<dir>/<handler>/<suite>/<case>/data.yaml`, as in the `tests`
+//! directory of this repository or the `general/<fork>/kzg` directory of the consensus-spec
+//! tests. Every case is run against the given settings, and its result is compared with the
+//! expected output, where no output means that the inputs must be rejected.
+
+use super::test_formats::{
+    blob_to_kzg_commitment_test, compute_blob_kzg_proof, compute_cells,
+    compute_cells_and_kzg_proofs, compute_kzg_proof, recover_cells_and_kzg_proofs,
+    verify_blob_kzg_proof, verify_blob_kzg_proof_batch, verify_cell_kzg_proof_batch,
+    verify_kzg_proof,
+};
+use crate::{Bytes48, Error, KzgSettings};
+use std::fs;
+use std::io;
+use std::path::{Path, PathBuf};
+
+/// A handler of the KZG test vectors, which tests one function.
+#[derive(Debug, Copy, Clone, Hash, PartialEq, Eq)]
+pub enum SpecTestHandler {
+    BlobToKzgCommitment,
+    ComputeKzgProof,
+    ComputeBlobKzgProof,
+    VerifyKzgProof,
+    VerifyBlobKzgProof,
+    VerifyBlobKzgProofBatch,
+    ComputeCells,
+    ComputeCellsAndKzgProofs,
+    RecoverCellsAndKzgProofs,
+    VerifyCellKzgProofBatch,
+}
+
+/// The result of a single test case.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct SpecTestResult {
+    pub handler: SpecTestHandler,
+    /// The path of the `data.yaml` file of the case.
+    pub path: PathBuf,
+    /// Why the case failed, if it did.
+    pub outcome: Result<(), String>,
+}
+
+impl SpecTestResult {
+    pub fn passed(&self) -> bool {
+        self.outcome.is_ok()
+    }
+}
+
+/// Compares the result of a call with the expected output of a case.
+fn check<T: PartialEq>(result: Result<T, Error>, expected: Option<T>) -> Result<(), String> {
+    match (result, expected) {
+        (Ok(output), Some(expected)) if output == expected => Ok(()),
+        (Ok(_), Some(_)) => Err("The output does not match the expected output".into()),
+        (Ok(_), None) => Err("Expected the inputs to be rejected".into()),
+        (Err(_), None) => Ok(()),
+        (Err(err), Some(_)) => Err(format!("Expected an output, got error: {err}")),
+    }
+}
+
+fn parse<'a, T: serde::Deserialize<'a>>(yaml: &'a str) -> Result<T, String> {
+    serde_yaml::from_str(yaml).map_err(|err| format!("Invalid test data: {err}"))
+}
+
+/// Maps an error parsing the expected output of a case to a failure of the case.
+fn output<T>(output: Result<Option<T>, Error>) -> Result<Option<T>, String> {
+    output.map_err(|err| format!("Invalid test data: {err}"))
+}
+
+impl SpecTestHandler {
+    pub const ALL: [Self; 10] = [
+        Self::BlobToKzgCommitment,
+        Self::ComputeKzgProof,
+        Self::ComputeBlobKzgProof,
+        Self::VerifyKzgProof,
+        Self::VerifyBlobKzgProof,
+        Self::VerifyBlobKzgProofBatch,
+        Self::ComputeCells,
+        Self::ComputeCellsAndKzgProofs,
+        Self::RecoverCellsAndKzgProofs,
+        Self::VerifyCellKzgProofBatch,
+    ];
+
+    /// The name of the handler, which is the name of its directory.
+    pub fn name(&self) -> &'static str {
+        match self {
+            Self::BlobToKzgCommitment => "blob_to_kzg_commitment",
+            Self::ComputeKzgProof => "compute_kzg_proof",
+            Self::ComputeBlobKzgProof => "compute_blob_kzg_proof",
+            Self::VerifyKzgProof => "verify_kzg_proof",
+            Self::VerifyBlobKzgProof => "verify_blob_kzg_proof",
+            Self::VerifyBlobKzgProofBatch => "verify_blob_kzg_proof_batch",
+            Self::ComputeCells => "compute_cells",
+            Self::ComputeCellsAndKzgProofs => "compute_cells_and_kzg_proofs",
+            Self::RecoverCellsAndKzgProofs => "recover_cells_and_kzg_proofs",
+            Self::VerifyCellKzgProofBatch => "verify_cell_kzg_proof_batch",
+        }
+    }
+
+    /// Runs a case of this handler, given the contents of its `data.yaml` file.
+    pub fn run_case(&self, yaml: &str, kzg_settings: &KzgSettings) -> Result<(), String> {
+        match self {
+            Self::BlobToKzgCommitment => {
+                let test: blob_to_kzg_commitment_test::Test = parse(yaml)?;
+                let result = test
+                    .input
+                    .get_blob()
+                    .and_then(|blob| kzg_settings.blob_to_kzg_commitment(&blob))
+                    .map(|commitment| commitment.to_bytes());
+                check(result, output(test.get_output())?)
+            }
+            Self::ComputeKzgProof => {
+                let test: compute_kzg_proof::Test = parse(yaml)?;
+                let result = (|| {
+                    let (proof, y) = kzg_settings
+                        .compute_kzg_proof(&test.input.get_blob()?, &test.input.get_z()?)?;
+                    Ok((proof.to_bytes(), y))
+                })();
+                check(result, output(test.get_output())?)
+            }
+            Self::ComputeBlobKzgProof => {
+                let test: compute_blob_kzg_proof::Test = parse(yaml)?;
+                let result = (|| {
+                    kzg_settings.compute_blob_kzg_proof(
+                        &test.input.get_blob()?,
+                        &test.input.get_commitment()?,
+                    )
+                })()
+                .map(|proof| proof.to_bytes());
+                check(result, output(test.get_output())?)
+            }
+            Self::VerifyKzgProof => {
+                let test: verify_kzg_proof::Test = parse(yaml)?;
+                let result = (|| {
+                    kzg_settings.verify_kzg_proof(
+                        &test.input.get_commitment()?,
+                        &test.input.get_z()?,
+                        &test.input.get_y()?,
+                        &test.input.get_proof()?,
+                    )
+                })();
+                check(result, test.get_output())
+            }
+            Self::VerifyBlobKzgProof => {
+                let test: verify_blob_kzg_proof::Test = parse(yaml)?;
+                let result = (|| {
+                    kzg_settings.verify_blob_kzg_proof(
+                        &test.input.get_blob()?,
+                        &test.input.get_commitment()?,
+                        &test.input.get_proof()?,
+                    )
+                })();
+                check(result, test.get_output())
+            }
+            Self::VerifyBlobKzgProofBatch => {
+                let test: verify_blob_kzg_proof_batch::Test = parse(yaml)?;
+                let result = (|| {
+                    kzg_settings.verify_blob_kzg_proof_batch(
+                        &test.input.get_blobs()?,
+                        &test.input.get_commitments()?,
+                        &test.input.get_proofs()?,
+                    )
+                })();
+                check(result, test.get_output())
+            }
+            Self::ComputeCells => {
+                let test: compute_cells::Test = parse(yaml)?;
+                let result = test
+                    .input
+                    .get_blob()
+                    .and_then(|blob| kzg_settings.compute_cells(&blob))
+                    .map(|cells| cells.to_vec());
+                check(result, output(test.get_output())?)
+            }
+            Self::ComputeCellsAndKzgProofs => {
+                let test: compute_cells_and_kzg_proofs::Test = parse(yaml)?;
+                let result = test
+                    .input
+                    .get_blob()
+                    .and_then(|blob| kzg_settings.compute_cells_and_kzg_proofs(&blob))
+                    .map(|(cells, proofs)| (cells.to_vec(), proofs_to_bytes(&*proofs)));
+                check(result, output(test.get_output())?)
+            }
+            Self::RecoverCellsAndKzgProofs => {
+                let test: recover_cells_and_kzg_proofs::Test = parse(yaml)?;
+                let result = (|| {
+                    kzg_settings.recover_cells_and_kzg_proofs(
+                        &test.input.get_cell_indices()?,
+                        &test.input.get_cells()?,
+                    )
+                })()
+                .map(|(cells, proofs)| (cells.to_vec(), proofs_to_bytes(&*proofs)));
+                check(result, output(test.get_output())?)
+            }
+            Self::VerifyCellKzgProofBatch => {
+                let test: verify_cell_kzg_proof_batch::Test = parse(yaml)?;
+                let result = (|| {
+                    kzg_settings.verify_cell_kzg_proof_batch(
+                        &test.input.get_commitments()?,
+                        &test.input.get_cell_indices()?,
+                        &test.input.get_cells()?,
+                        &test.input.get_proofs()?,
+                    )
+                })();
+                check(result, test.get_output())
+            }
+        }
+    }
+}
+
+fn proofs_to_bytes(proofs: &[crate::KzgProof]) -> Vec<Bytes48> {
+    proofs.iter().map(|proof| proof.to_bytes()).collect()
+}
+
+/// Returns the subdirectories of `dir`, sorted by name.
+fn subdirectories(dir: &Path) -> io::Result<Vec<PathBuf>> {
+    let mut dirs = Vec::new();
+    for entry in fs::read_dir(dir)? {
+        let path = entry?.path();
+        if path.is_dir() {
+            dirs.push(path);
+        }
+    }
+    dirs.sort();
+    Ok(dirs)
+}
+
+/// Runs every case of the KZG test vectors in `dir` against `kzg_settings`.
+///
+/// Handlers without a directory in `dir` are skipped, so that the vectors of forks which do not
+/// test every function can be run. A case whose `data.yaml` cannot be read or parsed fails.
+pub fn run_spec_tests(dir: &Path, kzg_settings: &KzgSettings) -> io::Result<Vec<SpecTestResult>> {
+    let mut results = Vec::new();
+    for handler in SpecTestHandler::ALL {
+        let handler_dir = dir.join(handler.name());
+        if !handler_dir.is_dir() {
+            continue;
+        }
+        for suite_dir in subdirectories(&handler_dir)? {
+            for case_dir in subdirectories(&suite_dir)? {
+                let path = case_dir.join("data.yaml");
+                let outcome = match fs::read_to_string(&path) {
+                    Ok(yaml) => handler.run_case(&yaml, kzg_settings),
+                    Err(err) => Err(format!("Cannot read test data: {err}")),
+                };
+                results.push(SpecTestResult {
+                    handler,
+                    path,
+                    outcome,
+                });
+            }
+        }
+    }
+    Ok(results)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn load_kzg_settings() -> KzgSettings {
+        KzgSettings::load_trusted_setup_file(Path::new("src/trusted_setup.txt"), 0).unwrap()
+    }
+
+    #[test]
+    fn test_run_spec_tests() {
+        let kzg_settings = load_kzg_settings();
+        let results = run_spec_tests(Path::new("tests"), &kzg_settings).unwrap();
+        for handler in SpecTestHandler::ALL {
+            assert!(results.iter().any(|result| result.handler == handler));
+        }
+        for result in &results {
+            assert!(result.passed(), "{result:?}");
+        }
+
+        assert!(
+            run_spec_tests(Path::new("tests/does_not_exist"), &kzg_settings)
+                .unwrap()
+                .is_empty()
+        );
+    }
+
+    #[test]
+    fn test_run_case_failures() {
+        let kzg_settings = load_kzg_settings();
+        let handler = SpecTestHandler::VerifyKzgProof;
+        let path = Path::new("tests/verify_kzg_proof/kzg-mainnet")
+            .join("verify_kzg_proof_case_correct_proof_0_0/data.yaml");
+        let yaml = fs::read_to_string(path).unwrap();
+        assert_eq!(handler.run_case(&yaml, &kzg_settings), Ok(()));
+
+        // The wrong expected output, or no output where the inputs are valid.
+        let wrong = yaml.replace("output: true", "output: false");
+        assert!(handler.run_case(&wrong, &kzg_settings).is_err());
+        let missing = yaml.replace("output: true", "output: null");
+        assert!(handler.run_case(&missing, &kzg_settings).is_err());
+
+        // Test data of another handler.
+        assert!(SpecTestHandler::ComputeCells
+            .run_case(&yaml, &kzg_settings)
+            .is_err());
+        assert!(handler.run_case("not: [yaml", &kzg_settings).is_err());
+
+        // A malformed expected output fails the case instead of panicking.
+        let path = Path::new("tests/blob_to_kzg_commitment/kzg-mainnet")
+            .join("blob_to_kzg_commitment_case_valid_blob_0/data.yaml");
+        let yaml = fs::read_to_string(path).unwrap();
+        let handler = SpecTestHandler::BlobToKzgCommitment;
+        assert_eq!(handler.run_case(&yaml, &kzg_settings), Ok(()));
+        let malformed = yaml.replace("output: '0x", "output: '0xzz");
+        assert_ne!(malformed, yaml);
+        let outcome = handler.run_case(&malformed, &kzg_settings);
+        assert!(outcome.unwrap_err().starts_with("Invalid test data"));
+    }
+}
@@ -0,0 +1,273 @@
+//! SSZ encoding, decoding and `hash_tree_root` for the basic types in this crate.
+//!
+//! The types are encoded as the consensus-spec containers define them: `Blob` and `Cell` are
+//! `ByteVector`s, commitments and proofs are `Bytes48`, and lists of them are `List[T, N]`.
+
+use super::merkle::{byte_vector_root, merkleize, mix_in_length};
+use super::MAX_BLOB_COMMITMENTS_PER_BLOCK;
+use crate::{Blob, Bytes32, Bytes48, Cell, Error, KzgCommitment, KzgProof};
+use crate::{BYTES_PER_BLOB, BYTES_PER_CELL, BYTES_PER_COMMITMENT, BYTES_PER_PROOF};
+use alloc::vec::Vec;
+use core::ops::Deref;
+
+/// A type with a fixed-size SSZ encoding.
+pub trait SszFixed: Sized {
+    /// The length of the SSZ encoding in bytes.
+    const SSZ_LEN: usize;
+
+    /// Appends the SSZ encoding to `buf`.
+    fn ssz_append(&self, buf: &mut Vec<u8>);
+
+    /// Decodes the value from exactly [`Self::SSZ_LEN`] bytes.
+    fn from_ssz_bytes(bytes: &[u8]) -> Result<Self, Error>;
+
+    /// Returns the SSZ `hash_tree_root` of the value.
+    fn hash_tree_root(&self) -> Bytes32;
+
+    /// Returns the SSZ encoding.
+    fn to_ssz_bytes(&self) -> Vec<u8> {
+        let mut buf = Vec::with_capacity(Self::SSZ_LEN);
+        self.ssz_append(&mut buf);
+        buf
+    }
+}
+
+fn check_len(bytes: &[u8], expected: usize) -> Result<(), Error> {
+    if bytes.len() != expected {
+        return Err(Error::InvalidBytesLength(format!(
+            "Invalid SSZ length. Expected {expected} got {}",
+            bytes.len()
+        )));
+    }
+    Ok(())
+}
+
+macro_rules! impl_ssz_fixed {
+    ($type:ty, $len:expr) => {
+        impl SszFixed for $type {
+            const SSZ_LEN: usize = $len;
+
+            fn ssz_append(&self, buf: &mut Vec<u8>) {
+                buf.extend_from_slice(&self.bytes);
+            }
+
+            fn from_ssz_bytes(bytes: &[u8]) -> Result<Self, Error> {
+                check_len(bytes, Self::SSZ_LEN)?;
+                let mut value = Self::default();
+                value.bytes.copy_from_slice(bytes);
+                Ok(value)
+            }
+
+            fn hash_tree_root(&self) -> Bytes32 {
+                Bytes32 {
+                    bytes: byte_vector_root(&self.bytes),
+                }
+            }
+        }
+    };
+}
+
+impl_ssz_fixed!(Bytes32, 32);
+impl_ssz_fixed!(Bytes48, 48);
+impl_ssz_fixed!(KzgCommitment, BYTES_PER_COMMITMENT);
+impl_ssz_fixed!(KzgProof, BYTES_PER_PROOF);
+impl_ssz_fixed!(Blob, BYTES_PER_BLOB);
+impl_ssz_fixed!(Cell, BYTES_PER_CELL);
+
+/// An SSZ `List[T, N]` of fixed-size items.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct SszList<T, const N: usize> {
+    items: Vec<T>,
+}
+
+/// The blob KZG commitments of a block body or data column sidecar.
+pub type BlobKzgCommitments = SszList<KzgCommitment, MAX_BLOB_COMMITMENTS_PER_BLOCK>;
+
+/// The cells of a data column, one per blob.
+pub type DataColumnCells = SszList<Cell, MAX_BLOB_COMMITMENTS_PER_BLOCK>;
+
+/// The KZG proofs of a data column, one per blob.
+pub type DataColumnProofs = SszList<KzgProof, MAX_BLOB_COMMITMENTS_PER_BLOCK>;
+
+impl<T: SszFixed, const N: usize> SszList<T, N> {
+    /// Creates a list, checking that there are at most `N` items.
+    pub fn new(items: Vec<T>) -> Result<Self, Error> {
+        if items.len() > N {
+            return Err(Error::InvalidBytesLength(format!(
+                "List has {} items, more than the limit of {N}",
+                items.len()
+            )));
+        }
+        Ok(Self { items })
+    }
+
+    pub fn into_inner(self) -> Vec<T> {
+        self.items
+    }
+
+    /// Returns the SSZ encoding, which is the concatenation of the items.
+    pub fn to_ssz_bytes(&self) -> Vec<u8> {
+        let mut buf = Vec::with_capacity(self.items.len() * T::SSZ_LEN);
+        for item in &self.items {
+            item.ssz_append(&mut buf);
+        }
+        buf
+    }
+
+    /// Decodes a list from the concatenation of its items.
+    pub fn from_ssz_bytes(bytes: &[u8]) -> Result<Self, Error> {
+        if !bytes.len().is_multiple_of(T::SSZ_LEN) {
+            return Err(Error::InvalidBytesLength(format!(
+                "Invalid SSZ length. Expected a multiple of {} got {}",
+                T::SSZ_LEN,
+                bytes.len(),
+            )));
+        }
+        let items = bytes
+            .chunks_exact(T::SSZ_LEN)
+            .map(T::from_ssz_bytes)
+            .collect::<Result<Vec<_>, _>>()?;
+        Self::new(items)
+    }
+
+    /// Returns the SSZ `hash_tree_root` of the list.
+    pub fn hash_tree_root(&self) -> Bytes32 {
+        let roots = self
+            .items
+            .iter()
+            .map(|item| item.hash_tree_root().bytes)
+            .collect();
+        Bytes32 {
+            bytes: mix_in_length(&merkleize(roots, N), self.items.len()),
+        }
+    }
+}
+
+impl<T, const N: usize> Default for SszList<T, N> {
+    fn default() -> Self {
+        Self { items: Vec::new() }
+    }
+}
+
+impl<T, const N: usize> Deref for SszList<T, N> {
+    type Target = [T];
+
+    fn deref(&self) -> &Self::Target {
+        &self.items
+    }
+}
+
+impl<T: SszFixed, const N: usize> TryFrom<Vec<T>> for SszList<T, N> {
+    type Error = Error;
+
+    fn try_from(items: Vec<T>) -> Result<Self, Self::Error> {
+        Self::new(items)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::FIELD_ELEMENTS_PER_BLOB;
+
+    fn sha256(input: &[u8]) -> [u8; 32] {
+        let mut out = [0u8; 32];
+        unsafe { blst::blst_sha256(out.as_mut_ptr(), input.as_ptr(), input.len()) };
+        out
+    }
+
+    fn zero_hash(depth: usize) -> [u8; 32] {
+        let mut zero = [0u8; 32];
+        for _ in 0..depth {
+            zero = sha256(&[zero, zero].concat());
+        }
+        zero
+    }
+
+    #[test]
+    fn test_ssz_fixed_roundtrip() {
+        let commitment = KzgCommitment::from([7; BYTES_PER_COMMITMENT]);
+        let encoded = commitment.to_ssz_bytes();
+        assert_eq!(encoded, vec![7; BYTES_PER_COMMITMENT]);
+        let decoded = KzgCommitment::from_ssz_bytes(&encoded).unwrap();
+        assert_eq!(decoded.bytes, commitment.bytes);
+
+        let cell = Cell::new([3; BYTES_PER_CELL]);
+        assert_eq!(Cell::from_ssz_bytes(&cell.to_ssz_bytes()).unwrap(), cell);
+        let blob = Blob::default();
+        assert_eq!(Blob::from_ssz_bytes(&blob.to_ssz_bytes()).unwrap(), blob);
+
+        assert!(KzgProof::from_ssz_bytes(&[0; BYTES_PER_PROOF - 1]).is_err());
+        assert!(Cell::from_ssz_bytes(&[0; BYTES_PER_CELL + 1]).is_err());
+    }
+
+    #[test]
+    fn test_ssz_fixed_hash_tree_root() {
+        // Zero byte vectors hash to the zero hash of their depth.
+        let root = KzgProof::default().hash_tree_root();
+        assert_eq!(
+            hex::encode(root.bytes),
+            "f5a5fd42d16a20302798ef6ed309979b43003d2320d9f0e8ea9831a92759fb4b"
+        );
+        assert_eq!(Cell::default().hash_tree_root().bytes, zero_hash(6));
+        let depth = FIELD_ELEMENTS_PER_BLOB.trailing_zeros() as usize;
+        assert_eq!(Blob::default().hash_tree_root().bytes, zero_hash(depth));
+
+        // A Bytes32 is its own root, and a Bytes48 is padded to two chunks.
+        let bytes = Bytes32::from([9; 32]);
+        assert_eq!(bytes.hash_tree_root(), bytes);
+        let commitment = KzgCommitment::from([1; BYTES_PER_COMMITMENT]);
+        let mut chunks = [0u8; 64];
+        chunks[..BYTES_PER_COMMITMENT].copy_from_slice(&[1; BYTES_PER_COMMITMENT]);
+        assert_eq!(commitment.hash_tree_root().bytes, sha256(&chunks));
+    }
+
+    #[test]
+    fn test_ssz_list() {
+        let depth = MAX_BLOB_COMMITMENTS_PER_BLOCK.trailing_zeros() as usize;
+        let empty = BlobKzgCommitments::default();
+        assert!(empty.to_ssz_bytes().is_empty());
+        assert_eq!(
+            empty.hash_tree_root().bytes,
+            sha256(&[zero_hash(depth), [0; 32]].concat())
+        );
+
+        let commitments: Vec<KzgCommitment> = (0..3u8)
+            .map(|i| KzgCommitment::from([i; BYTES_PER_COMMITMENT]))
+            .collect();
+        let list = BlobKzgCommitments::new(commitments.clone()).unwrap();
+        let encoded = list.to_ssz_bytes();
+        assert_eq!(encoded.len(), 3 * BYTES_PER_COMMITMENT);
+        let decoded = BlobKzgCommitments::from_ssz_bytes(&encoded).unwrap();
+        assert!(decoded
+            .iter()
+            .zip(&commitments)
+            .all(|(a, b)| a.bytes == b.bytes));
+
+        // Three roots padded to four, then padded with zero hashes up to the limit.
+        let roots: Vec<[u8; 32]> = commitments
+            .iter()
+            .map(|c| c.hash_tree_root().bytes)
+            .collect();
+        let mut root = sha256(
+            &[
+                sha256(&[roots[0], roots[1]].concat()),
+                sha256(&[roots[2], [0; 32]].concat()),
+            ]
+            .concat(),
+        );
+        for i in 2..depth {
+            root = sha256(&[root, zero_hash(i)].concat());
+        }
+        let mut length = [0u8; 32];
+        length[0] = 3;
+        assert_eq!(
+            list.hash_tree_root().bytes,
+            sha256(&[root, length].concat())
+        );
+
+        assert!(DataColumnCells::from_ssz_bytes(&[0; BYTES_PER_CELL + 1]).is_err());
+        assert!(SszList::<Bytes32, 2>::new(vec![Bytes32::default(); 3]).is_err());
+        assert!(SszList::<Bytes32, 2>::from_ssz_bytes(&[0; 96]).is_err());
+    }
+}
@@ -28,7 +28,7 @@ pub struct Test<'a> {
 }
 
 impl Test<'_> {
-    pub fn get_output(&self) -> Option<Bytes48> {
-        self.output.map(|s| Bytes48::from_hex(s).unwrap())
+    pub fn get_output(&self) -> Result<Option<Bytes48>, Error> {
+        self.output.map(Bytes48::from_hex).transpose()
     }
 }
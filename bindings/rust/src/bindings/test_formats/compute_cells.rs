@@ -24,12 +24,15 @@ pub struct Test<'a> {
 }
 
 impl Test<'_> {
-    pub fn get_output(&self) -> Option<Vec<Cell>> {
-        self.output.clone().map(|cells| {
-            cells
-                .iter()
-                .map(|s| Cell::from_hex(s).unwrap())
-                .collect::<Vec<Cell>>()
-        })
+    pub fn get_output(&self) -> Result<Option<Vec<Cell>>, Error> {
+        self.output
+            .as_ref()
+            .map(|cells| {
+                cells
+                    .iter()
+                    .map(|s| Cell::from_hex(s))
+                    .collect::<Result<Vec<Cell>, Error>>()
+            })
+            .transpose()
     }
 }
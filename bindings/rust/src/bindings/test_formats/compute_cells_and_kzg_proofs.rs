@@ -1,5 +1,6 @@
 #![allow(dead_code)]
 
+use super::CellsAndProofs;
 use crate::{Blob, Bytes48, Cell, Error};
 use alloc::string::String;
 use alloc::vec::Vec;
@@ -24,18 +25,21 @@ pub struct Test<'a> {
 }
 
 impl Test<'_> {
-    pub fn get_output(&self) -> Option<(Vec<Cell>, Vec<Bytes48>)> {
-        self.output.clone().map(|(cells, proofs)| {
-            (
-                cells
-                    .iter()
-                    .map(|s| Cell::from_hex(s).unwrap())
-                    .collect::<Vec<Cell>>(),
-                proofs
-                    .iter()
-                    .map(|s| Bytes48::from_hex(s).unwrap())
-                    .collect::<Vec<Bytes48>>(),
-            )
-        })
+    pub fn get_output(&self) -> Result<Option<CellsAndProofs>, Error> {
+        self.output
+            .as_ref()
+            .map(|(cells, proofs)| {
+                Ok((
+                    cells
+                        .iter()
+                        .map(|s| Cell::from_hex(s))
+                        .collect::<Result<Vec<Cell>, Error>>()?,
+                    proofs
+                        .iter()
+                        .map(|s| Bytes48::from_hex(s))
+                        .collect::<Result<Vec<Bytes48>, Error>>()?,
+                ))
+            })
+            .transpose()
     }
 }
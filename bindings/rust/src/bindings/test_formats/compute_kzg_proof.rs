@@ -28,12 +28,9 @@ pub struct Test<'a> {
 }
 
 impl Test<'_> {
-    pub fn get_output(&self) -> Option<(Bytes48, Bytes32)> {
-        self.output.map(|(proof, y)| {
-            (
-                Bytes48::from_hex(proof).unwrap(),
-                Bytes32::from_hex(y).unwrap(),
-            )
-        })
+    pub fn get_output(&self) -> Result<Option<(Bytes48, Bytes32)>, Error> {
+        self.output
+            .map(|(proof, y)| Ok((Bytes48::from_hex(proof)?, Bytes32::from_hex(y)?)))
+            .transpose()
     }
 }
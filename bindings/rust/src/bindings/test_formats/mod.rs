@@ -1,3 +1,8 @@
+//! Parsers for the `data.yaml` files of the consensus-spec KZG test vectors.
+
+use crate::{Bytes48, Cell};
+use alloc::vec::Vec;
+
 /* EIP-4844 */
 pub mod blob_to_kzg_commitment_test;
 pub mod compute_blob_kzg_proof;
@@ -11,3 +16,6 @@ pub mod compute_cells;
 pub mod compute_cells_and_kzg_proofs;
 pub mod recover_cells_and_kzg_proofs;
 pub mod verify_cell_kzg_proof_batch;
+
+/// The expected cells and proofs of a case.
+pub type CellsAndProofs = (Vec<Cell>, Vec<Bytes48>);
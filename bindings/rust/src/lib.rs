@@ -13,12 +13,40 @@ mod bindings;
 #[cfg(feature = "ethereum_kzg_settings")]
 mod ethereum_kzg_settings;
 
+#[cfg(feature = "reference")]
+pub mod reference;
+
+#[cfg(feature = "test-utils")]
+pub mod test_utils;
+
 // Expose relevant types with idiomatic names.
 pub use bindings::{
     KZGCommitment as KzgCommitment, KZGProof as KzgProof, KZGSettings as KzgSettings,
+    ValidatedKZGCommitment as ValidatedKzgCommitment, ValidatedKZGProof as ValidatedKzgProof,
     C_KZG_RET as CkzgError,
 };
 
+// Expose the serde helpers for the boxed per-cell arrays.
+#[cfg(feature = "serde")]
+pub use bindings::serde::{ext_blob_array, option_ext_blob_array};
+
+// Expose the SSZ encoding of the types.
+#[cfg(feature = "ssz")]
+pub use bindings::ssz::{BlobKzgCommitments, DataColumnCells, DataColumnProofs, SszFixed, SszList};
+
+// Expose the RLP encoding of the blob transaction network wrapper.
+#[cfg(feature = "rlp")]
+pub use bindings::rlp::{
+    decode_network_wrapper, decode_network_wrapper_v1, encode_network_wrapper,
+    encode_network_wrapper_v1, NETWORK_WRAPPER_VERSION_CELL_PROOFS,
+};
+
+// Expose the runner for the consensus-spec test vectors.
+#[cfg(feature = "spec-tests")]
+pub use bindings::spec_tests::{run_spec_tests, SpecTestHandler, SpecTestResult};
+#[cfg(feature = "spec-tests")]
+pub use bindings::test_formats;
+
 // Expose the default settings.
 #[cfg(feature = "ethereum_kzg_settings")]
 pub use ethereum_kzg_settings::{ethereum_kzg_settings, ethereum_kzg_settings_arc};
@@ -27,6 +55,12 @@ pub use ethereum_kzg_settings::{ethereum_kzg_settings, ethereum_kzg_settings_arc
 pub use bindings::{
     BYTES_PER_BLOB, BYTES_PER_CELL, BYTES_PER_COMMITMENT, BYTES_PER_FIELD_ELEMENT, BYTES_PER_PROOF,
     CELLS_PER_EXT_BLOB, FIELD_ELEMENTS_PER_BLOB, FIELD_ELEMENTS_PER_CELL,
+    FIELD_ELEMENTS_PER_EXT_BLOB, KZG_COMMITMENT_INCLUSION_PROOF_DEPTH,
+    MAX_BLOB_COMMITMENTS_PER_BLOCK,
 };
 // Expose the remaining relevant types.
-pub use bindings::{Blob, Bytes32, Bytes48, Cell, Error};
+pub use bindings::{
+    Blob, BlobSidecar, BlobTransactionSidecar, BlobTransactionSidecarV1, BlobsBundleV1,
+    BlobsBundleV2, Bytes32, Bytes48, Cell, CommitmentBuilder, DataColumn, Error, ExtendedBlob,
+    VerifiedRecovery,
+};
@@ -0,0 +1,737 @@
+//! A slow reference implementation of the KZG functions, written directly against `blst`.
+//!
+//! It follows the consensus specs as literally as is practical and shares no code with the C
+//! library, so that it can serve as an independent oracle in tests and fuzzing. It is far too
+//! slow for anything else.
+
+use crate::bindings::parse_trusted_setup_bytes;
+use crate::{
+    Blob, Bytes32, Cell, Error, KzgCommitment, KzgProof, BYTES_PER_CELL, BYTES_PER_COMMITMENT,
+    BYTES_PER_FIELD_ELEMENT, CELLS_PER_EXT_BLOB, FIELD_ELEMENTS_PER_BLOB, FIELD_ELEMENTS_PER_CELL,
+    FIELD_ELEMENTS_PER_EXT_BLOB,
+};
+use alloc::vec::Vec;
+use blst::{blst_fr, blst_p1, blst_p1_affine, blst_scalar, MultiPoint, BLST_ERROR};
+use core::ops::{Add, Mul, Sub};
+
+/// The order of the BLS12-381 scalar field, as little-endian 64-bit limbs.
+const BLS_MODULUS: [u64; 4] = [
+    0xffffffff00000001,
+    0x53bda402fffe5bfe,
+    0x3339d80809a1d805,
+    0x73eda753299d7d48,
+];
+
+/// The generator of the multiplicative group of the field.
+const PRIMITIVE_ROOT_OF_UNITY: u64 = 7;
+
+/// An element of the BLS12-381 scalar field.
+#[derive(Debug, Default, Copy, Clone, PartialEq, Eq)]
+struct Fr(blst_fr);
+
+impl Fr {
+    fn from_u64(value: u64) -> Self {
+        let mut out = blst_fr::default();
+        unsafe { blst::blst_fr_from_uint64(&mut out, [value, 0, 0, 0].as_ptr()) };
+        Self(out)
+    }
+
+    fn one() -> Self {
+        Self::from_u64(1)
+    }
+
+    /// Decodes a big-endian field element, which must be less than the modulus.
+    fn from_bytes(bytes: &[u8; BYTES_PER_FIELD_ELEMENT]) -> Result<Self, Error> {
+        let mut scalar = blst_scalar::default();
+        let mut out = blst_fr::default();
+        unsafe {
+            blst::blst_scalar_from_bendian(&mut scalar, bytes.as_ptr());
+            if !blst::blst_scalar_fr_check(&scalar) {
+                return Err(Error::InvalidFieldElement(format!(
+                    "0x{} is not less than the BLS modulus",
+                    hex::encode(bytes)
+                )));
+            }
+            blst::blst_fr_from_scalar(&mut out, &scalar);
+        }
+        Ok(Self(out))
+    }
+
+    fn to_scalar(self) -> blst_scalar {
+        let mut scalar = blst_scalar::default();
+        unsafe { blst::blst_scalar_from_fr(&mut scalar, &self.0) };
+        scalar
+    }
+
+    fn to_bytes(self) -> [u8; BYTES_PER_FIELD_ELEMENT] {
+        let mut out = [0; BYTES_PER_FIELD_ELEMENT];
+        unsafe { blst::blst_bendian_from_scalar(out.as_mut_ptr(), &self.to_scalar()) };
+        out
+    }
+
+    fn is_zero(self) -> bool {
+        self == Self::default()
+    }
+
+    /// Returns the multiplicative inverse, or zero for zero.
+    fn inverse(self) -> Self {
+        let mut out = blst_fr::default();
+        unsafe { blst::blst_fr_inverse(&mut out, &self.0) };
+        Self(out)
+    }
+
+    /// Raises to the power of a little-endian 256-bit exponent.
+    fn pow(self, exponent: &[u64; 4]) -> Self {
+        let mut result = Self::one();
+        for limb in exponent.iter().rev() {
+            for bit in (0..64).rev() {
+                result = result * result;
+                if (limb >> bit) & 1 == 1 {
+                    result = result * self;
+                }
+            }
+        }
+        result
+    }
+}
+
+impl Add for Fr {
+    type Output = Self;
+
+    fn add(self, other: Self) -> Self {
+        let mut out = blst_fr::default();
+        unsafe { blst::blst_fr_add(&mut out, &self.0, &other.0) };
+        Self(out)
+    }
+}
+
+impl Sub for Fr {
+    type Output = Self;
+
+    fn sub(self, other: Self) -> Self {
+        let mut out = blst_fr::default();
+        unsafe { blst::blst_fr_sub(&mut out, &self.0, &other.0) };
+        Self(out)
+    }
+}
+
+impl Mul for Fr {
+    type Output = Self;
+
+    fn mul(self, other: Self) -> Self {
+        let mut out = blst_fr::default();
+        unsafe { blst::blst_fr_mul(&mut out, &self.0, &other.0) };
+        Self(out)
+    }
+}
+
+/// Decodes consecutive big-endian field elements.
+fn bytes_to_field_elements(bytes: &[u8]) -> Result<Vec<Fr>, Error> {
+    bytes
+        .chunks_exact(BYTES_PER_FIELD_ELEMENT)
+        .map(|chunk| {
+            let mut field_element = [0; BYTES_PER_FIELD_ELEMENT];
+            field_element.copy_from_slice(chunk);
+            Fr::from_bytes(&field_element)
+        })
+        .collect()
+}
+
+fn reverse_bits(n: usize, order: usize) -> usize {
+    debug_assert!(order.is_power_of_two() && order > 1);
+    n.reverse_bits() >> (usize::BITS - order.trailing_zeros())
+}
+
+fn bit_reversal_permutation<T: Copy>(values: &[T]) -> Vec<T> {
+    (0..values.len())
+        .map(|i| values[reverse_bits(i, values.len())])
+        .collect()
+}
+
+/// Returns the powers of a primitive root of unity of the given power-of-two order.
+fn compute_roots_of_unity(order: usize) -> Vec<Fr> {
+    debug_assert!(order.is_power_of_two());
+    // The exponent (BLS_MODULUS - 1) / order, where the order divides BLS_MODULUS - 1.
+    let shift = order.trailing_zeros();
+    let mut exponent = BLS_MODULUS;
+    exponent[0] -= 1;
+    if shift > 0 {
+        for i in 0..4 {
+            let high = exponent.get(i + 1).map_or(0, |limb| limb << (64 - shift));
+            exponent[i] = (exponent[i] >> shift) | high;
+        }
+    }
+    let root = Fr::from_u64(PRIMITIVE_ROOT_OF_UNITY).pow(&exponent);
+
+    let mut current = Fr::one();
+    (0..order)
+        .map(|_| {
+            let power = current;
+            current = current * root;
+            power
+        })
+        .collect()
+}
+
+fn fft_field_recursive(values: &[Fr], roots_of_unity: &[Fr]) -> Vec<Fr> {
+    if values.len() == 1 {
+        return values.to_vec();
+    }
+    let even_roots: Vec<Fr> = roots_of_unity.iter().step_by(2).copied().collect();
+    let even: Vec<Fr> = values.iter().step_by(2).copied().collect();
+    let odd: Vec<Fr> = values.iter().skip(1).step_by(2).copied().collect();
+    let left = fft_field_recursive(&even, &even_roots);
+    let right = fft_field_recursive(&odd, &even_roots);
+
+    let half = left.len();
+    let mut out = vec![Fr::default(); values.len()];
+    for (i, (x, y)) in left.into_iter().zip(right).enumerate() {
+        let y_times_root = y * roots_of_unity[i];
+        out[i] = x + y_times_root;
+        out[i + half] = x - y_times_root;
+    }
+    out
+}
+
+fn fft_field(values: &[Fr], roots_of_unity: &[Fr], inverse: bool) -> Vec<Fr> {
+    if !inverse {
+        return fft_field_recursive(values, roots_of_unity);
+    }
+    let mut inverse_roots = vec![roots_of_unity[0]];
+    inverse_roots.extend(roots_of_unity[1..].iter().rev());
+    let inverse_len = Fr::from_u64(values.len() as u64).inverse();
+    fft_field_recursive(values, &inverse_roots)
+        .into_iter()
+        .map(|x| x * inverse_len)
+        .collect()
+}
+
+/// Multiplies the i-th value by `factor` to the i-th power.
+fn shift_values(values: &[Fr], factor: Fr) -> Vec<Fr> {
+    let mut shift = Fr::one();
+    values
+        .iter()
+        .map(|&value| {
+            let shifted = value * shift;
+            shift = shift * factor;
+            shifted
+        })
+        .collect()
+}
+
+/// An FFT over the coset of the roots of unity shifted by [`PRIMITIVE_ROOT_OF_UNITY`].
+fn coset_fft_field(values: &[Fr], roots_of_unity: &[Fr], inverse: bool) -> Vec<Fr> {
+    let shift_factor = Fr::from_u64(PRIMITIVE_ROOT_OF_UNITY);
+    if inverse {
+        let values = fft_field(values, roots_of_unity, true);
+        shift_values(&values, shift_factor.inverse())
+    } else {
+        fft_field(&shift_values(values, shift_factor), roots_of_unity, false)
+    }
+}
+
+/// Returns the polynomial in coefficient form which vanishes exactly on `roots`.
+fn vanishing_polynomialcoeff(roots: &[Fr]) -> Vec<Fr> {
+    let mut polynomial = vec![Fr::one()];
+    for &root in roots {
+        // Multiply by (X - root).
+        let mut product = vec![Fr::default(); polynomial.len() + 1];
+        for (i, &coefficient) in polynomial.iter().enumerate() {
+            product[i + 1] = product[i + 1] + coefficient;
+            product[i] = product[i] - coefficient * root;
+        }
+        polynomial = product;
+    }
+    polynomial
+}
+
+/// Long division of polynomials in coefficient form, discarding the remainder.
+fn divide_polynomialcoeff(dividend: &[Fr], divisor: &[Fr]) -> Vec<Fr> {
+    let mut remainder = dividend.to_vec();
+    let divisor_degree = divisor.len() - 1;
+    let leading_inverse = divisor[divisor_degree].inverse();
+    let mut quotient = vec![Fr::default(); dividend.len() - divisor_degree];
+    for shift in (0..quotient.len()).rev() {
+        let quot = remainder[shift + divisor_degree] * leading_inverse;
+        quotient[shift] = quot;
+        for (i, &coefficient) in divisor.iter().enumerate() {
+            if !coefficient.is_zero() {
+                remainder[shift + i] = remainder[shift + i] - coefficient * quot;
+            }
+        }
+    }
+    quotient
+}
+
+fn cell_from_field_elements(field_elements: &[Fr]) -> Cell {
+    let mut bytes = [0; BYTES_PER_CELL];
+    for (chunk, field_element) in bytes
+        .chunks_exact_mut(BYTES_PER_FIELD_ELEMENT)
+        .zip(field_elements)
+    {
+        chunk.copy_from_slice(&field_element.to_bytes());
+    }
+    Cell::new(bytes)
+}
+
+/// The G1 points of a trusted setup, as used by the reference implementation.
+pub struct ReferenceSettings {
+    g1_monomial: Vec<blst_p1_affine>,
+    g1_lagrange_brp: Vec<blst_p1_affine>,
+}
+
+impl ReferenceSettings {
+    /// Creates the settings from flat arrays of `FIELD_ELEMENTS_PER_BLOB` compressed G1 points
+    /// in monomial and in Lagrange form.
+    pub fn new(g1_monomial_bytes: &[u8], g1_lagrange_bytes: &[u8]) -> Result<Self, Error> {
+        let g1_lagrange = Self::decompress_points(g1_lagrange_bytes)?;
+        Ok(Self {
+            g1_monomial: Self::decompress_points(g1_monomial_bytes)?,
+            g1_lagrange_brp: bit_reversal_permutation(&g1_lagrange),
+        })
+    }
+
+    /// Parses the contents of a KZG trusted setup file, in the format of
+    /// [`KzgSettings::load_trusted_setup_file`](crate::KzgSettings::load_trusted_setup_file).
+    pub fn parse_trusted_setup(trusted_setup: &str) -> Result<Self, Error> {
+        let (g1_lagrange_bytes, _, g1_monomial_bytes) = parse_trusted_setup_bytes(trusted_setup)?;
+        Self::new(g1_monomial_bytes.as_ref(), g1_lagrange_bytes.as_ref())
+    }
+
+    fn decompress_points(bytes: &[u8]) -> Result<Vec<blst_p1_affine>, Error> {
+        if bytes.len() != FIELD_ELEMENTS_PER_BLOB * BYTES_PER_COMMITMENT {
+            return Err(Error::InvalidTrustedSetup(format!(
+                "Invalid number of G1 point bytes. Expected {} got {}",
+                FIELD_ELEMENTS_PER_BLOB * BYTES_PER_COMMITMENT,
+                bytes.len()
+            )));
+        }
+        bytes
+            .chunks_exact(BYTES_PER_COMMITMENT)
+            .map(|chunk| {
+                let mut point = blst_p1_affine::default();
+                let res = unsafe { blst::blst_p1_uncompress(&mut point, chunk.as_ptr()) };
+                if res != BLST_ERROR::BLST_SUCCESS || !unsafe { blst::blst_p1_affine_in_g1(&point) }
+                {
+                    return Err(Error::InvalidTrustedSetup(format!(
+                        "Invalid G1 point 0x{}",
+                        hex::encode(chunk)
+                    )));
+                }
+                Ok(point)
+            })
+            .collect()
+    }
+
+    /// Computes the linear combination of the first `scalars.len()` points, compressed.
+    fn g1_lincomb(points: &[blst_p1_affine], scalars: &[Fr]) -> [u8; BYTES_PER_COMMITMENT] {
+        let scalar_bytes: Vec<u8> = scalars
+            .iter()
+            .flat_map(|scalar| scalar.to_scalar().b)
+            .collect();
+        let result: blst_p1 = points[..scalars.len()].mult(&scalar_bytes, 255);
+        let mut out = [0; BYTES_PER_COMMITMENT];
+        unsafe { blst::blst_p1_compress(out.as_mut_ptr(), &result) };
+        out
+    }
+
+    pub fn blob_to_kzg_commitment(&self, blob: &Blob) -> Result<KzgCommitment, Error> {
+        let polynomial = bytes_to_field_elements(&blob[..])?;
+        Ok(KzgCommitment::from(Self::g1_lincomb(
+            &self.g1_lagrange_brp,
+            &polynomial,
+        )))
+    }
+
+    /// Computes the proof of the evaluation of the blob at `z`, and the evaluation `y`.
+    pub fn compute_kzg_proof(
+        &self,
+        blob: &Blob,
+        z_bytes: &Bytes32,
+    ) -> Result<(KzgProof, Bytes32), Error> {
+        let polynomial = bytes_to_field_elements(&blob[..])?;
+        let z = Fr::from_bytes(z_bytes)?;
+        let roots_of_unity_brp =
+            bit_reversal_permutation(&compute_roots_of_unity(FIELD_ELEMENTS_PER_BLOB));
+        let y = evaluate_polynomial_in_evaluation_form(&polynomial, z, &roots_of_unity_brp);
+
+        let quotient: Vec<Fr> = polynomial
+            .iter()
+            .zip(&roots_of_unity_brp)
+            .map(|(&p, &root)| {
+                if root == z {
+                    compute_quotient_eval_within_domain(z, &polynomial, y, &roots_of_unity_brp)
+                } else {
+                    (p - y) * (root - z).inverse()
+                }
+            })
+            .collect();
+        let proof = KzgProof::from(Self::g1_lincomb(&self.g1_lagrange_brp, &quotient));
+        Ok((proof, Bytes32::from(y.to_bytes())))
+    }
+
+    /// Computes the cells of the extended blob.
+    pub fn compute_cells(&self, blob: &Blob) -> Result<Vec<Cell>, Error> {
+        let polynomial = bytes_to_field_elements(&blob[..])?;
+        let polynomial_coeff = polynomial_eval_to_coeff(&polynomial);
+        Ok(compute_cells_polynomialcoeff(&polynomial_coeff))
+    }
+
+    /// Computes the cells of the extended blob and their proofs.
+    pub fn compute_cells_and_kzg_proofs(
+        &self,
+        blob: &Blob,
+    ) -> Result<(Vec<Cell>, Vec<KzgProof>), Error> {
+        let polynomial = bytes_to_field_elements(&blob[..])?;
+        let polynomial_coeff = polynomial_eval_to_coeff(&polynomial);
+        Ok(self.compute_cells_and_kzg_proofs_polynomialcoeff(&polynomial_coeff))
+    }
+
+    /// Recovers all of the cells and their proofs from at least half of the cells.
+    ///
+    /// As in the specs, every cell index must be given only once.
+    pub fn recover_cells_and_kzg_proofs(
+        &self,
+        cell_indices: &[u64],
+        cells: &[Cell],
+    ) -> Result<(Vec<Cell>, Vec<KzgProof>), Error> {
+        if cell_indices.len() != cells.len() {
+            return Err(Error::MismatchLength(format!(
+                "There are {} cell indices and {} cells",
+                cell_indices.len(),
+                cells.len()
+            )));
+        }
+        let mut present = [false; CELLS_PER_EXT_BLOB];
+        for &cell_index in cell_indices {
+            if cell_index >= CELLS_PER_EXT_BLOB as u64 {
+                return Err(Error::InvalidCellIndex(format!(
+                    "Cell index {cell_index} is not less than {CELLS_PER_EXT_BLOB}"
+                )));
+            }
+            if present[cell_index as usize] {
+                return Err(Error::InvalidCellIndex(format!(
+                    "Cell index {cell_index} was provided more than once"
+                )));
+            }
+            present[cell_index as usize] = true;
+        }
+        if cell_indices.len() < CELLS_PER_EXT_BLOB / 2 {
+            return Err(Error::InvalidCellIndex(format!(
+                "There are {} cells, fewer than the {} needed for recovery",
+                cell_indices.len(),
+                CELLS_PER_EXT_BLOB / 2
+            )));
+        }
+
+        let mut extended_evaluation_rbo = vec![Fr::default(); FIELD_ELEMENTS_PER_EXT_BLOB];
+        for (&cell_index, cell) in cell_indices.iter().zip(cells) {
+            let start = cell_index as usize * FIELD_ELEMENTS_PER_CELL;
+            extended_evaluation_rbo[start..start + FIELD_ELEMENTS_PER_CELL]
+                .copy_from_slice(&bytes_to_field_elements(&cell.to_bytes())?);
+        }
+        let missing_cell_indices: Vec<usize> = (0..CELLS_PER_EXT_BLOB)
+            .filter(|&cell_index| !present[cell_index])
+            .collect();
+        let polynomial_coeff =
+            recover_polynomialcoeff(&extended_evaluation_rbo, &missing_cell_indices);
+        Ok(self.compute_cells_and_kzg_proofs_polynomialcoeff(&polynomial_coeff))
+    }
+
+    fn compute_cells_and_kzg_proofs_polynomialcoeff(
+        &self,
+        polynomial_coeff: &[Fr],
+    ) -> (Vec<Cell>, Vec<KzgProof>) {
+        let roots_of_unity_brp =
+            bit_reversal_permutation(&compute_roots_of_unity(FIELD_ELEMENTS_PER_EXT_BLOB));
+        let proofs = roots_of_unity_brp
+            .chunks_exact(FIELD_ELEMENTS_PER_CELL)
+            .map(|coset| {
+                let denominator = vanishing_polynomialcoeff(coset);
+                let quotient = divide_polynomialcoeff(polynomial_coeff, &denominator);
+                KzgProof::from(Self::g1_lincomb(&self.g1_monomial, &quotient))
+            })
+            .collect();
+        (compute_cells_polynomialcoeff(polynomial_coeff), proofs)
+    }
+}
+
+fn evaluate_polynomial_in_evaluation_form(
+    polynomial: &[Fr],
+    z: Fr,
+    roots_of_unity_brp: &[Fr],
+) -> Fr {
+    // Within the domain, the evaluation is already known.
+    if let Some(index) = roots_of_unity_brp.iter().position(|&root| root == z) {
+        return polynomial[index];
+    }
+    let mut result = Fr::default();
+    for (&p, &root) in polynomial.iter().zip(roots_of_unity_brp) {
+        result = result + p * root * (z - root).inverse();
+    }
+    let width = polynomial.len() as u64;
+    let z_pow_width_minus_one = z.pow(&[width, 0, 0, 0]) - Fr::one();
+    result * z_pow_width_minus_one * Fr::from_u64(width).inverse()
+}
+
+/// The quotient at `z`, where `z` is a root of unity of the domain and the quotient's
+/// denominator is zero.
+fn compute_quotient_eval_within_domain(
+    z: Fr,
+    polynomial: &[Fr],
+    y: Fr,
+    roots_of_unity_brp: &[Fr],
+) -> Fr {
+    let mut result = Fr::default();
+    for (&p, &root) in polynomial.iter().zip(roots_of_unity_brp) {
+        if root == z {
+            continue;
+        }
+        let numerator = (p - y) * root;
+        let denominator = z * (z - root);
+        result = result + numerator * denominator.inverse();
+    }
+    result
+}
+
+fn polynomial_eval_to_coeff(polynomial: &[Fr]) -> Vec<Fr> {
+    let roots_of_unity = compute_roots_of_unity(FIELD_ELEMENTS_PER_BLOB);
+    fft_field(&bit_reversal_permutation(polynomial), &roots_of_unity, true)
+}
+
+/// Evaluates the polynomial over the extended domain. In bit-reversed order, each cell's coset
+/// of the domain is a contiguous range, so the cells are consecutive chunks of the evaluations.
+fn compute_cells_polynomialcoeff(polynomial_coeff: &[Fr]) -> Vec<Cell> {
+    let roots_of_unity = compute_roots_of_unity(FIELD_ELEMENTS_PER_EXT_BLOB);
+    let mut extended_coeff = polynomial_coeff.to_vec();
+    extended_coeff.resize(FIELD_ELEMENTS_PER_EXT_BLOB, Fr::default());
+    let evaluations = fft_field(&extended_coeff, &roots_of_unity, false);
+    bit_reversal_permutation(&evaluations)
+        .chunks_exact(FIELD_ELEMENTS_PER_CELL)
+        .map(cell_from_field_elements)
+        .collect()
+}
+
+/// The polynomial in coefficient form over the extended domain which vanishes on the cosets of
+/// the missing cells.
+fn construct_vanishing_polynomial(missing_cell_indices: &[usize]) -> Vec<Fr> {
+    let roots_of_unity_reduced = compute_roots_of_unity(CELLS_PER_EXT_BLOB);
+    let roots: Vec<Fr> = missing_cell_indices
+        .iter()
+        .map(|&cell_index| roots_of_unity_reduced[reverse_bits(cell_index, CELLS_PER_EXT_BLOB)])
+        .collect();
+    let short_zero_poly = vanishing_polynomialcoeff(&roots);
+
+    let mut zero_poly_coeff = vec![Fr::default(); FIELD_ELEMENTS_PER_EXT_BLOB];
+    for (i, coefficient) in short_zero_poly.into_iter().enumerate() {
+        zero_poly_coeff[i * FIELD_ELEMENTS_PER_CELL] = coefficient;
+    }
+    zero_poly_coeff
+}
+
+/// Recovers the polynomial in coefficient form from the evaluations over the extended domain,
+/// in bit-reversed order, where the evaluations of the missing cells are zero.
+fn recover_polynomialcoeff(
+    extended_evaluation_rbo: &[Fr],
+    missing_cell_indices: &[usize],
+) -> Vec<Fr> {
+    let roots_of_unity_extended = compute_roots_of_unity(FIELD_ELEMENTS_PER_EXT_BLOB);
+    let extended_evaluation = bit_reversal_permutation(extended_evaluation_rbo);
+
+    let zero_poly_coeff = construct_vanishing_polynomial(missing_cell_indices);
+    let zero_poly_eval = fft_field(&zero_poly_coeff, &roots_of_unity_extended, false);
+
+    // (E * Z)(x) in evaluation form, then in coefficient form.
+    let extended_evaluation_times_zero: Vec<Fr> = zero_poly_eval
+        .iter()
+        .zip(&extended_evaluation)
+        .map(|(&a, &b)| a * b)
+        .collect();
+    let extended_evaluation_times_zero_coeffs = fft_field(
+        &extended_evaluation_times_zero,
+        &roots_of_unity_extended,
+        true,
+    );
+
+    // Divide by Z(x) over a coset, where Z(x) has no roots.
+    let extended_evaluations_over_coset = coset_fft_field(
+        &extended_evaluation_times_zero_coeffs,
+        &roots_of_unity_extended,
+        false,
+    );
+    let zero_poly_over_coset = coset_fft_field(&zero_poly_coeff, &roots_of_unity_extended, false);
+    let reconstructed_poly_over_coset: Vec<Fr> = extended_evaluations_over_coset
+        .iter()
+        .zip(&zero_poly_over_coset)
+        .map(|(&a, &b)| a * b.inverse())
+        .collect();
+    let mut reconstructed_poly_coeff = coset_fft_field(
+        &reconstructed_poly_over_coset,
+        &roots_of_unity_extended,
+        true,
+    );
+
+    reconstructed_poly_coeff.truncate(FIELD_ELEMENTS_PER_BLOB);
+    reconstructed_poly_coeff
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::bindings::tests::generate_random_blob;
+    use crate::{Bytes48, KzgSettings};
+    use rand::Rng;
+
+    fn load_settings() -> (KzgSettings, ReferenceSettings) {
+        let trusted_setup = std::fs::read_to_string("src/trusted_setup.txt").unwrap();
+        (
+            KzgSettings::parse_kzg_trusted_setup(&trusted_setup, 0).unwrap(),
+            ReferenceSettings::parse_trusted_setup(&trusted_setup).unwrap(),
+        )
+    }
+
+    /// The number of random blobs which each comparison is run over.
+    const NUM_BLOBS: usize = 3;
+
+    #[test]
+    fn test_reference_field() {
+        // The roots of unity have exactly the requested order.
+        let roots = compute_roots_of_unity(FIELD_ELEMENTS_PER_EXT_BLOB);
+        let root = roots[1];
+        assert_eq!(
+            root.pow(&[FIELD_ELEMENTS_PER_EXT_BLOB as u64, 0, 0, 0]),
+            Fr::one()
+        );
+        assert_ne!(
+            root.pow(&[FIELD_ELEMENTS_PER_BLOB as u64, 0, 0, 0]),
+            Fr::one()
+        );
+
+        let values: Vec<Fr> = (0..16).map(Fr::from_u64).collect();
+        let roots = compute_roots_of_unity(16);
+        let evaluations = fft_field(&values, &roots, false);
+        assert_eq!(fft_field(&evaluations, &roots, true), values);
+        let evaluations = coset_fft_field(&values, &roots, false);
+        assert_eq!(coset_fft_field(&evaluations, &roots, true), values);
+
+        let mut modulus = [0u8; BYTES_PER_FIELD_ELEMENT];
+        for (chunk, limb) in modulus.rchunks_exact_mut(8).zip(BLS_MODULUS) {
+            chunk.copy_from_slice(&limb.to_be_bytes());
+        }
+        assert!(Fr::from_bytes(&modulus).is_err());
+        modulus[BYTES_PER_FIELD_ELEMENT - 1] -= 1;
+        assert_eq!(Fr::from_bytes(&modulus).unwrap() + Fr::one(), Fr::default());
+    }
+
+    #[test]
+    fn test_reference_commitment_and_proof() {
+        let (kzg_settings, reference_settings) = load_settings();
+        let mut rng = rand::rng();
+
+        for _ in 0..NUM_BLOBS {
+            let blob = generate_random_blob(&mut rng);
+            let commitment = reference_settings.blob_to_kzg_commitment(&blob).unwrap();
+            assert_eq!(
+                commitment.to_bytes(),
+                kzg_settings
+                    .blob_to_kzg_commitment(&blob)
+                    .unwrap()
+                    .to_bytes()
+            );
+
+            // A random point, and a point within the domain.
+            let mut z = rng.random::<[u8; 32]>();
+            z[0] = 0;
+            let z = Bytes32::from(z);
+            let root = Bytes32::from(compute_roots_of_unity(FIELD_ELEMENTS_PER_BLOB)[5].to_bytes());
+            for z in [z, root] {
+                let (proof, y) = reference_settings.compute_kzg_proof(&blob, &z).unwrap();
+                let (expected_proof, expected_y) =
+                    kzg_settings.compute_kzg_proof(&blob, &z).unwrap();
+                assert_eq!(proof.to_bytes(), expected_proof.to_bytes());
+                assert_eq!(y, expected_y);
+            }
+        }
+
+        let blob = generate_random_blob(&mut rng);
+        let mut invalid_blob = generate_random_blob(&mut rng);
+        invalid_blob[..BYTES_PER_FIELD_ELEMENT].fill(0xff);
+        assert!(reference_settings
+            .blob_to_kzg_commitment(&invalid_blob)
+            .is_err());
+        assert!(kzg_settings.blob_to_kzg_commitment(&invalid_blob).is_err());
+        assert!(reference_settings
+            .compute_kzg_proof(&blob, &Bytes32::from([0xff; 32]))
+            .is_err());
+    }
+
+    #[test]
+    fn test_reference_cells_and_recovery() {
+        let (kzg_settings, reference_settings) = load_settings();
+        let mut rng = rand::rng();
+        let half = CELLS_PER_EXT_BLOB / 2;
+
+        for _ in 0..NUM_BLOBS {
+            let blob = generate_random_blob(&mut rng);
+            let (expected_cells, expected_proofs) =
+                kzg_settings.compute_cells_and_kzg_proofs(&blob).unwrap();
+            let expected_proofs: Vec<Bytes48> = expected_proofs
+                .iter()
+                .map(|proof| proof.to_bytes())
+                .collect();
+            let cells = reference_settings.compute_cells(&blob).unwrap();
+            assert_eq!(cells.as_slice(), expected_cells.as_slice());
+            let (cells, proofs) = reference_settings
+                .compute_cells_and_kzg_proofs(&blob)
+                .unwrap();
+            assert_eq!(cells.as_slice(), expected_cells.as_slice());
+            let proofs: Vec<Bytes48> = proofs.iter().map(|proof| proof.to_bytes()).collect();
+            assert_eq!(proofs, expected_proofs);
+
+            // Recover from a random half of the cells, which takes the general path in C, and
+            // from the second half, which C re-extends directly.
+            let mut random_half: Vec<u64> =
+                rand::seq::index::sample(&mut rng, CELLS_PER_EXT_BLOB, half)
+                    .into_iter()
+                    .map(|i| i as u64)
+                    .collect();
+            random_half.sort_unstable();
+            let second_half: Vec<u64> = (half as u64..CELLS_PER_EXT_BLOB as u64).collect();
+            for cell_indices in [random_half, second_half] {
+                let partial_cells: Vec<Cell> = cell_indices
+                    .iter()
+                    .map(|&i| expected_cells[i as usize])
+                    .collect();
+                let (recovered_cells, recovered_proofs) = reference_settings
+                    .recover_cells_and_kzg_proofs(&cell_indices, &partial_cells)
+                    .unwrap();
+                let (c_cells, c_proofs) = kzg_settings
+                    .recover_cells_and_kzg_proofs(&cell_indices, &partial_cells)
+                    .unwrap();
+                assert_eq!(recovered_cells.as_slice(), expected_cells.as_slice());
+                assert_eq!(c_cells.as_slice(), expected_cells.as_slice());
+                let recovered_proofs: Vec<Bytes48> = recovered_proofs
+                    .iter()
+                    .map(|proof| proof.to_bytes())
+                    .collect();
+                let c_proofs: Vec<Bytes48> =
+                    c_proofs.iter().map(|proof| proof.to_bytes()).collect();
+                assert_eq!(recovered_proofs, expected_proofs);
+                assert_eq!(c_proofs, expected_proofs);
+            }
+        }
+
+        // Too few cells, or a duplicate cell index.
+        let blob = generate_random_blob(&mut rng);
+        let cells = reference_settings.compute_cells(&blob).unwrap();
+        let mut cell_indices: Vec<u64> = (0..half as u64).collect();
+        let partial_cells = &cells[..half];
+        assert!(reference_settings
+            .recover_cells_and_kzg_proofs(&cell_indices[1..], &partial_cells[1..])
+            .is_err());
+        cell_indices[1] = cell_indices[0];
+        assert!(reference_settings
+            .recover_cells_and_kzg_proofs(&cell_indices, partial_cells)
+            .is_err());
+    }
+}
@@ -0,0 +1,371 @@
+//! Generators of valid and invalid inputs, for the tests of crates which use this one.
+//!
+//! The plain [`arbitrary::Arbitrary`] impls of the byte types produce random bytes, which are
+//! almost never canonical blobs or valid points, so tests built on them rarely get past input
+//! validation. The functions here produce inputs which are valid, or invalid in one specific way,
+//! and the newtypes wrap them as `Arbitrary` impls and proptest strategies.
+
+use crate::{
+    Blob, Bytes32, Bytes48, Cell, KzgCommitment, KzgProof, KzgSettings, BYTES_PER_FIELD_ELEMENT,
+    FIELD_ELEMENTS_PER_BLOB, FIELD_ELEMENTS_PER_CELL,
+};
+use blst::{blst_p1_affine, blst_p1_uncompress, BLST_ERROR};
+use proptest::prelude::{any, BoxedStrategy, Strategy};
+use rand::rngs::StdRng;
+use rand::{Rng, SeedableRng};
+use std::sync::Arc;
+
+/// The most significant byte of the BLS modulus. Field elements whose first byte is smaller are
+/// canonical, and those whose first byte is larger are not.
+const BLS_MODULUS_FIRST_BYTE: u8 = 0x73;
+
+/// The most significant byte of the base field modulus, without the flag bits.
+const FP_MODULUS_FIRST_BYTE: u8 = 0x1a;
+
+/// The flag of a compressed point.
+const COMPRESSION_FLAG: u8 = 0x80;
+
+/// The flag of the sign of the y coordinate of a compressed point.
+const SIGN_FLAG: u8 = 0x20;
+
+fn canonical_field_element_bytes<R: Rng + ?Sized>(rng: &mut R, out: &mut [u8]) {
+    rng.fill(&mut out[..]);
+    out[0] %= BLS_MODULUS_FIRST_BYTE;
+}
+
+/// Returns a random field element which is smaller than the BLS modulus.
+pub fn random_field_element<R: Rng + ?Sized>(rng: &mut R) -> Bytes32 {
+    let mut bytes = [0u8; BYTES_PER_FIELD_ELEMENT];
+    canonical_field_element_bytes(rng, &mut bytes);
+    Bytes32::new(bytes)
+}
+
+/// Returns a random field element which is larger than the BLS modulus.
+pub fn non_canonical_field_element<R: Rng + ?Sized>(rng: &mut R) -> Bytes32 {
+    let mut bytes = [0u8; BYTES_PER_FIELD_ELEMENT];
+    rng.fill(&mut bytes[..]);
+    bytes[0] = rng.random_range(BLS_MODULUS_FIRST_BYTE + 1..=u8::MAX);
+    Bytes32::new(bytes)
+}
+
+/// Returns a random blob, all of whose field elements are canonical.
+pub fn random_blob<R: Rng + ?Sized>(rng: &mut R) -> Blob {
+    let mut blob = Blob::default();
+    for field_element in blob.chunks_exact_mut(BYTES_PER_FIELD_ELEMENT) {
+        canonical_field_element_bytes(rng, field_element);
+    }
+    blob
+}
+
+/// Returns a random blob, one of whose field elements is not canonical.
+pub fn non_canonical_blob<R: Rng + ?Sized>(rng: &mut R) -> Blob {
+    let mut blob = random_blob(rng);
+    let index = rng.random_range(0..FIELD_ELEMENTS_PER_BLOB) * BYTES_PER_FIELD_ELEMENT;
+    blob[index..index + BYTES_PER_FIELD_ELEMENT]
+        .copy_from_slice(&*non_canonical_field_element(rng));
+    blob
+}
+
+/// Returns a random blob with its commitment and blob proof.
+pub fn random_blob_commitment_and_proof<R: Rng + ?Sized>(
+    kzg_settings: &KzgSettings,
+    rng: &mut R,
+) -> (Blob, KzgCommitment, KzgProof) {
+    let blob = random_blob(rng);
+    let (commitment, proof) = kzg_settings
+        .blob_to_kzg_commitment_and_proof(&blob)
+        .expect("a canonical blob is valid");
+    (blob, commitment, proof)
+}
+
+/// Returns a copy of the cell with one field element replaced by another canonical one, so that
+/// the cell is still well formed but no longer matches its proof.
+pub fn corrupt_cell<R: Rng + ?Sized>(cell: &Cell, rng: &mut R) -> Cell {
+    let mut bytes = cell.to_bytes();
+    let index = rng.random_range(0..FIELD_ELEMENTS_PER_CELL) * BYTES_PER_FIELD_ELEMENT;
+    let field_element = &mut bytes[index..index + BYTES_PER_FIELD_ELEMENT];
+    let original = field_element.to_vec();
+    loop {
+        canonical_field_element_bytes(rng, field_element);
+        if *field_element != original[..] {
+            break;
+        }
+    }
+    Cell::new(bytes)
+}
+
+fn is_off_curve(bytes: &[u8; 48]) -> bool {
+    let mut point = blst_p1_affine::default();
+    let res = unsafe { blst_p1_uncompress(&mut point, bytes.as_ptr()) };
+    res == BLST_ERROR::BLST_POINT_NOT_ON_CURVE
+}
+
+/// Makes the bytes a compressed point whose x coordinate is smaller than the field modulus.
+fn to_compressed_point(bytes: &mut [u8; 48]) {
+    bytes[0] = COMPRESSION_FLAG | (bytes[0] & SIGN_FLAG) | (bytes[0] % FP_MODULUS_FIRST_BYTE);
+}
+
+/// Returns a well formed compressed G1 point, which is not on the curve.
+pub fn off_curve_point<R: Rng + ?Sized>(rng: &mut R) -> Bytes48 {
+    // About half of the x coordinates are not on the curve.
+    loop {
+        let mut bytes = [0u8; 48];
+        rng.fill(&mut bytes[..]);
+        to_compressed_point(&mut bytes);
+        if is_off_curve(&bytes) {
+            return Bytes48::new(bytes);
+        }
+    }
+}
+
+/// A blob, all of whose field elements are canonical.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct CanonicalBlob(pub Blob);
+
+/// A blob, one of whose field elements is not canonical.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct NonCanonicalBlob(pub Blob);
+
+/// A field element which is smaller than the BLS modulus.
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub struct CanonicalFieldElement(pub Bytes32);
+
+/// A field element which is larger than the BLS modulus.
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub struct NonCanonicalFieldElement(pub Bytes32);
+
+/// A well formed compressed G1 point, which is not on the curve.
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub struct OffCurvePoint(pub Bytes48);
+
+/// Fills the blob with canonical field elements. Blobs are filled in place, because they are too
+/// big to be copied around on the stack in debug builds.
+fn fill_canonical_blob(
+    u: &mut arbitrary::Unstructured<'_>,
+    blob: &mut Blob,
+) -> arbitrary::Result<()> {
+    u.fill_buffer(&mut **blob)?;
+    for field_element in blob.chunks_exact_mut(BYTES_PER_FIELD_ELEMENT) {
+        field_element[0] %= BLS_MODULUS_FIRST_BYTE;
+    }
+    Ok(())
+}
+
+impl arbitrary::Arbitrary<'_> for CanonicalBlob {
+    fn arbitrary(u: &mut arbitrary::Unstructured<'_>) -> arbitrary::Result<Self> {
+        let mut blob = Self(Blob::default());
+        fill_canonical_blob(u, &mut blob.0)?;
+        Ok(blob)
+    }
+}
+
+impl arbitrary::Arbitrary<'_> for NonCanonicalBlob {
+    fn arbitrary(u: &mut arbitrary::Unstructured<'_>) -> arbitrary::Result<Self> {
+        let mut blob = Self(Blob::default());
+        fill_canonical_blob(u, &mut blob.0)?;
+        let index = u.choose_index(FIELD_ELEMENTS_PER_BLOB)? * BYTES_PER_FIELD_ELEMENT;
+        let field_element = NonCanonicalFieldElement::arbitrary(u)?.0;
+        blob.0[index..index + BYTES_PER_FIELD_ELEMENT].copy_from_slice(&*field_element);
+        Ok(blob)
+    }
+}
+
+impl arbitrary::Arbitrary<'_> for CanonicalFieldElement {
+    fn arbitrary(u: &mut arbitrary::Unstructured<'_>) -> arbitrary::Result<Self> {
+        let mut bytes = [0u8; BYTES_PER_FIELD_ELEMENT];
+        u.fill_buffer(&mut bytes)?;
+        bytes[0] %= BLS_MODULUS_FIRST_BYTE;
+        Ok(Self(Bytes32::new(bytes)))
+    }
+}
+
+impl arbitrary::Arbitrary<'_> for NonCanonicalFieldElement {
+    fn arbitrary(u: &mut arbitrary::Unstructured<'_>) -> arbitrary::Result<Self> {
+        let mut bytes = [0u8; BYTES_PER_FIELD_ELEMENT];
+        u.fill_buffer(&mut bytes)?;
+        bytes[0] = u.int_in_range(BLS_MODULUS_FIRST_BYTE + 1..=u8::MAX)?;
+        Ok(Self(Bytes32::new(bytes)))
+    }
+}
+
+impl arbitrary::Arbitrary<'_> for OffCurvePoint {
+    fn arbitrary(u: &mut arbitrary::Unstructured<'_>) -> arbitrary::Result<Self> {
+        let mut bytes = [0u8; 48];
+        u.fill_buffer(&mut bytes)?;
+        to_compressed_point(&mut bytes);
+        if is_off_curve(&bytes) {
+            return Ok(Self(Bytes48::new(bytes)));
+        }
+        // Search from the given bytes, so that the same input always gives the same point.
+        let seed = u64::from_le_bytes(bytes[40..].try_into().unwrap());
+        Ok(Self(off_curve_point(&mut StdRng::seed_from_u64(seed))))
+    }
+}
+
+/// Implements the proptest `Arbitrary` trait with a generator seeded by proptest.
+macro_rules! impl_proptest_arbitrary {
+    ($type:ident, $generator:ident) => {
+        impl proptest::arbitrary::Arbitrary for $type {
+            type Parameters = ();
+            type Strategy = BoxedStrategy<Self>;
+
+            fn arbitrary_with(_: Self::Parameters) -> Self::Strategy {
+                any::<u64>()
+                    .prop_map(|seed| $type($generator(&mut StdRng::seed_from_u64(seed))))
+                    .boxed()
+            }
+        }
+    };
+}
+
+impl_proptest_arbitrary!(CanonicalBlob, random_blob);
+impl_proptest_arbitrary!(NonCanonicalBlob, non_canonical_blob);
+impl_proptest_arbitrary!(CanonicalFieldElement, random_field_element);
+impl_proptest_arbitrary!(NonCanonicalFieldElement, non_canonical_field_element);
+impl_proptest_arbitrary!(OffCurvePoint, off_curve_point);
+
+/// Returns a strategy for random blobs with their commitments and blob proofs.
+pub fn blob_commitment_and_proof_strategy(
+    kzg_settings: Arc<KzgSettings>,
+) -> impl Strategy<Value = (Blob, KzgCommitment, KzgProof)> {
+    any::<u64>().prop_map(move |seed| {
+        random_blob_commitment_and_proof(&kzg_settings, &mut StdRng::seed_from_u64(seed))
+    })
+}
+
+/// Returns a strategy for a cell of a random blob, corrupted so that it no longer matches its
+/// proof, with its index, the blob commitment and the proof of the original cell.
+pub fn corrupted_cell_strategy(
+    kzg_settings: Arc<KzgSettings>,
+) -> impl Strategy<Value = (Bytes48, u64, Cell, Bytes48)> {
+    any::<u64>().prop_map(move |seed| {
+        let mut rng = StdRng::seed_from_u64(seed);
+        let blob = random_blob(&mut rng);
+        let commitment = kzg_settings.blob_to_kzg_commitment(&blob).unwrap();
+        let (cells, proofs) = kzg_settings.compute_cells_and_kzg_proofs(&blob).unwrap();
+        let index = rng.random_range(0..cells.len());
+        let cell = corrupt_cell(&cells[index], &mut rng);
+        (
+            commitment.to_bytes(),
+            index as u64,
+            cell,
+            proofs[index].to_bytes(),
+        )
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{Error, ValidatedKzgCommitment};
+    use arbitrary::Arbitrary;
+    use proptest::test_runner::{Config, TestRunner};
+    use std::path::Path;
+
+    fn load_kzg_settings() -> KzgSettings {
+        KzgSettings::load_trusted_setup_file(Path::new("src/trusted_setup.txt"), 0).unwrap()
+    }
+
+    #[test]
+    fn test_generators() {
+        let kzg_settings = load_kzg_settings();
+        let mut rng = rand::rng();
+
+        let (blob, commitment, proof) = random_blob_commitment_and_proof(&kzg_settings, &mut rng);
+        assert!(kzg_settings
+            .verify_blob_kzg_proof(&blob, &commitment.to_bytes(), &proof.to_bytes())
+            .unwrap());
+        assert!(matches!(
+            kzg_settings.blob_to_kzg_commitment(&non_canonical_blob(&mut rng)),
+            Err(Error::CError(_))
+        ));
+
+        let z = random_field_element(&mut rng);
+        assert!(kzg_settings.compute_kzg_proof(&blob, &z).is_ok());
+        let z = non_canonical_field_element(&mut rng);
+        assert!(kzg_settings.compute_kzg_proof(&blob, &z).is_err());
+
+        let point = off_curve_point(&mut rng);
+        assert!(ValidatedKzgCommitment::new(&point).is_err());
+
+        let (cells, proofs) = kzg_settings.compute_cells_and_kzg_proofs(&blob).unwrap();
+        let cell = corrupt_cell(&cells[0], &mut rng);
+        assert_ne!(cell, cells[0]);
+        let verify = |cell: &Cell| {
+            kzg_settings.verify_cell_kzg_proof_batch(
+                &[commitment.to_bytes()],
+                &[0],
+                core::slice::from_ref(cell),
+                &[proofs[0].to_bytes()],
+            )
+        };
+        assert!(verify(&cells[0]).unwrap());
+        assert!(!verify(&cell).unwrap());
+    }
+
+    #[test]
+    fn test_arbitrary() {
+        let kzg_settings = load_kzg_settings();
+        let data = vec![0xff; 2 * crate::BYTES_PER_BLOB];
+
+        let mut u = arbitrary::Unstructured::new(&data);
+        let blob = CanonicalBlob::arbitrary(&mut u).unwrap();
+        assert!(kzg_settings.blob_to_kzg_commitment(&blob.0).is_ok());
+        let blob = NonCanonicalBlob::arbitrary(&mut u).unwrap();
+        assert!(kzg_settings.blob_to_kzg_commitment(&blob.0).is_err());
+
+        let mut u = arbitrary::Unstructured::new(&data);
+        let z = u.arbitrary::<CanonicalFieldElement>().unwrap().0;
+        assert_eq!(z[0], 0xff % BLS_MODULUS_FIRST_BYTE);
+        let z = u.arbitrary::<NonCanonicalFieldElement>().unwrap().0;
+        assert!(z[0] > BLS_MODULUS_FIRST_BYTE);
+        for data in [[0u8; 48], [0xff; 48], [0x5a; 48]] {
+            let point = arbitrary::Unstructured::new(&data)
+                .arbitrary::<OffCurvePoint>()
+                .unwrap()
+                .0;
+            assert!(ValidatedKzgCommitment::new(&point).is_err());
+        }
+    }
+
+    #[test]
+    fn test_strategies() {
+        let kzg_settings = Arc::new(load_kzg_settings());
+        let mut runner = TestRunner::new(Config::with_cases(4));
+
+        runner
+            .run(&any::<OffCurvePoint>(), |point| {
+                assert!(ValidatedKzgCommitment::new(&point.0).is_err());
+                Ok(())
+            })
+            .unwrap();
+        runner
+            .run(&any::<NonCanonicalFieldElement>(), |z| {
+                assert!(z.0[0] > BLS_MODULUS_FIRST_BYTE);
+                Ok(())
+            })
+            .unwrap();
+        runner
+            .run(
+                &blob_commitment_and_proof_strategy(kzg_settings.clone()),
+                |(blob, commitment, proof)| {
+                    assert!(kzg_settings
+                        .verify_blob_kzg_proof(&blob, &commitment.to_bytes(), &proof.to_bytes())
+                        .unwrap());
+                    Ok(())
+                },
+            )
+            .unwrap();
+        runner
+            .run(
+                &corrupted_cell_strategy(kzg_settings.clone()),
+                |(commitment, index, cell, proof)| {
+                    assert!(!kzg_settings
+                        .verify_cell_kzg_proof_batch(&[commitment], &[index], &[cell], &[proof])
+                        .unwrap());
+                    Ok(())
+                },
+            )
+            .unwrap();
+    }
+}
@@ -0,0 +1,63 @@
+// Run with the following command:
+// cargo fuzz run fuzz_deserialize
+
+#![no_main]
+extern crate core;
+
+use c_kzg::{
+    Blob, BlobTransactionSidecar, BlobTransactionSidecarV1, BlobsBundleV1, BlobsBundleV2, Bytes32,
+    Bytes48, Cell, KzgCommitment, KzgProof, ValidatedKzgCommitment, ValidatedKzgProof,
+};
+use libfuzzer_sys::fuzz_target;
+use serde::de::DeserializeOwned;
+use serde::Serialize;
+use std::fmt::Debug;
+
+///////////////////////////////////////////////////////////////////////////////
+// Helper Functions
+///////////////////////////////////////////////////////////////////////////////
+
+/// Deserializes `data` as a `T` with a human-readable and two binary formats, and checks that it
+/// round trips in each format which accepts it.
+fn check<T, B>(data: &[u8], to_bytes: impl Fn(&T) -> B)
+where
+    T: Serialize + DeserializeOwned,
+    B: PartialEq + Debug,
+{
+    if let Ok(value) = serde_json::from_slice::<T>(data) {
+        let json = serde_json::to_vec(&value).unwrap();
+        let decoded: T = serde_json::from_slice(&json).unwrap();
+        assert_eq!(to_bytes(&decoded), to_bytes(&value));
+    }
+    if let Ok(value) = bincode::deserialize::<T>(data) {
+        let encoded = bincode::serialize(&value).unwrap();
+        let decoded: T = bincode::deserialize(&encoded).unwrap();
+        assert_eq!(to_bytes(&decoded), to_bytes(&value));
+    }
+    // CBOR also reads bytes from arrays of integers, which bincode never produces.
+    if let Ok(value) = ciborium::from_reader::<T, _>(data) {
+        let mut encoded = Vec::new();
+        ciborium::into_writer(&value, &mut encoded).unwrap();
+        let decoded: T = ciborium::from_reader(encoded.as_slice()).unwrap();
+        assert_eq!(to_bytes(&decoded), to_bytes(&value));
+    }
+}
+
+///////////////////////////////////////////////////////////////////////////////
+// Fuzz Target
+///////////////////////////////////////////////////////////////////////////////
+
+fuzz_target!(|data: &[u8]| {
+    check(data, |bytes: &Bytes32| *bytes);
+    check(data, |bytes: &Bytes48| *bytes);
+    check(data, |blob: &Blob| blob.clone());
+    check(data, |cell: &Cell| *cell);
+    check(data, |commitment: &KzgCommitment| commitment.to_bytes());
+    check(data, |proof: &KzgProof| proof.to_bytes());
+    check(data, |commitment: &ValidatedKzgCommitment| *commitment);
+    check(data, |proof: &ValidatedKzgProof| *proof);
+    check(data, |sidecar: &BlobTransactionSidecar| sidecar.clone());
+    check(data, |sidecar: &BlobTransactionSidecarV1| sidecar.clone());
+    check(data, |bundle: &BlobsBundleV1| bundle.clone());
+    check(data, |bundle: &BlobsBundleV2| bundle.clone());
+});
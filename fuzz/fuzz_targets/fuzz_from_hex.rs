@@ -0,0 +1,49 @@
+// Run with the following command:
+// cargo fuzz run fuzz_from_hex
+
+#![no_main]
+extern crate core;
+
+use libfuzzer_sys::fuzz_target;
+
+///////////////////////////////////////////////////////////////////////////////
+// Helper Functions
+///////////////////////////////////////////////////////////////////////////////
+
+/// Checks the bytes from a `from_hex` result against decoding the string with the `hex` crate.
+fn check(hex_str: &str, len: usize, result: Result<Vec<u8>, c_kzg::Error>) {
+    let trimmed_str = hex_str.strip_prefix("0x").unwrap_or(hex_str);
+    let expected = hex::decode(trimmed_str)
+        .ok()
+        .filter(|bytes| bytes.len() == len);
+
+    match (&result, expected) {
+        (Ok(value), Some(bytes)) => {
+            assert_eq!(*value, bytes);
+            // The hex string must round trip, ignoring case.
+            assert_eq!(hex::encode(value), trimmed_str.to_lowercase());
+        }
+        (Err(_), None) => {
+            // Both rejected the string.
+        }
+        _ => {
+            // There is a disagreement.
+            panic!("mismatch for {:?}: {:?}", hex_str, result.as_ref().err());
+        }
+    }
+}
+
+///////////////////////////////////////////////////////////////////////////////
+// Fuzz Target
+///////////////////////////////////////////////////////////////////////////////
+
+fuzz_target!(|hex_str: &str| {
+    let bytes32 = c_kzg::Bytes32::from_hex(hex_str).map(|bytes| bytes.to_vec());
+    check(hex_str, 32, bytes32);
+    let bytes48 = c_kzg::Bytes48::from_hex(hex_str).map(|bytes| bytes.to_vec());
+    check(hex_str, 48, bytes48);
+    let cell = c_kzg::Cell::from_hex(hex_str).map(|cell| cell.to_bytes().to_vec());
+    check(hex_str, c_kzg::BYTES_PER_CELL, cell);
+    let blob = c_kzg::Blob::from_hex(hex_str).map(|blob| blob.to_vec());
+    check(hex_str, c_kzg::BYTES_PER_BLOB, blob);
+});
@@ -0,0 +1,137 @@
+// Run with the following command:
+// cargo fuzz run fuzz_load_trusted_setup
+
+#![no_main]
+extern crate core;
+
+use arbitrary::Arbitrary;
+use lazy_static::lazy_static;
+use libfuzzer_sys::fuzz_target;
+use std::env;
+use std::fs;
+use std::path::PathBuf;
+
+///////////////////////////////////////////////////////////////////////////////
+// Helper Functions
+///////////////////////////////////////////////////////////////////////////////
+
+fn get_root_dir() -> PathBuf {
+    if let Ok(manifest) = env::var("CARGO_MANIFEST_DIR") {
+        // When running locally
+        PathBuf::from(manifest)
+            .parent()
+            .expect("CARGO_MANIFEST_DIR has no parent")
+            .to_path_buf()
+    } else {
+        // When running with oss-fuzz
+        env::current_dir().expect("Failed to get current directory")
+    }
+}
+
+///////////////////////////////////////////////////////////////////////////////
+// Initialization
+///////////////////////////////////////////////////////////////////////////////
+
+/// The points of the trusted setup, as the flat byte arrays that `load_trusted_setup` takes.
+struct TrustedSetupBytes {
+    g1_monomial: Vec<u8>,
+    g1_lagrange: Vec<u8>,
+    g2_monomial: Vec<u8>,
+}
+
+lazy_static! {
+    static ref TRUSTED_SETUP: TrustedSetupBytes = {
+        let trusted_setup_file = get_root_dir().join("src").join("trusted_setup.txt");
+        let contents = fs::read_to_string(trusted_setup_file).unwrap();
+        let mut lines = contents.lines().map(str::trim).filter(|line| !line.is_empty());
+        let num_g1_points: usize = lines.next().unwrap().parse().unwrap();
+        let num_g2_points: usize = lines.next().unwrap().parse().unwrap();
+        let mut read_points = |n: usize| -> Vec<u8> {
+            (0..n)
+                .flat_map(|_| hex::decode(lines.next().unwrap()).unwrap())
+                .collect()
+        };
+        // The file lists the Lagrange points first.
+        let g1_lagrange = read_points(num_g1_points);
+        let g2_monomial = read_points(num_g2_points);
+        let g1_monomial = read_points(num_g1_points);
+        TrustedSetupBytes {
+            g1_monomial,
+            g1_lagrange,
+            g2_monomial,
+        }
+    };
+}
+
+///////////////////////////////////////////////////////////////////////////////
+// Fuzz Target
+///////////////////////////////////////////////////////////////////////////////
+
+#[derive(Arbitrary, Debug, Copy, Clone)]
+enum Points {
+    G1Monomial,
+    G1Lagrange,
+    G2Monomial,
+}
+
+/// Flips bits in one byte of the points. Random points are almost never valid, so we start from
+/// the real trusted setup and corrupt it a little instead.
+#[derive(Arbitrary, Debug)]
+struct Mutation {
+    points: Points,
+    offset: u32,
+    xor: u8,
+}
+
+#[derive(Arbitrary, Debug)]
+struct Input {
+    mutations: Vec<Mutation>,
+    truncate: Option<(Points, u32)>,
+}
+
+fuzz_target!(|input: Input| {
+    let mut g1_monomial = TRUSTED_SETUP.g1_monomial.clone();
+    let mut g1_lagrange = TRUSTED_SETUP.g1_lagrange.clone();
+    let mut g2_monomial = TRUSTED_SETUP.g2_monomial.clone();
+    let mut modified = false;
+
+    for mutation in &input.mutations {
+        let bytes = match mutation.points {
+            Points::G1Monomial => &mut g1_monomial,
+            Points::G1Lagrange => &mut g1_lagrange,
+            Points::G2Monomial => &mut g2_monomial,
+        };
+        let offset = mutation.offset as usize % bytes.len();
+        bytes[offset] ^= mutation.xor;
+        modified |= mutation.xor != 0;
+    }
+    if let Some((points, len)) = input.truncate {
+        let bytes = match points {
+            Points::G1Monomial => &mut g1_monomial,
+            Points::G1Lagrange => &mut g1_lagrange,
+            Points::G2Monomial => &mut g2_monomial,
+        };
+        bytes.truncate(len as usize % bytes.len());
+        modified = true;
+    }
+
+    let result =
+        c_kzg::KzgSettings::load_trusted_setup(&g1_monomial, &g1_lagrange, &g2_monomial, 0);
+    match result {
+        Ok(kzg_settings) => {
+            // If the setup was accepted, it must be usable.
+            let blob = c_kzg::Blob::default();
+            let commitment = kzg_settings.blob_to_kzg_commitment(&blob).unwrap();
+            let proof = kzg_settings
+                .compute_blob_kzg_proof(&blob, &commitment.to_bytes())
+                .unwrap();
+            assert!(kzg_settings
+                .verify_blob_kzg_proof(&blob, &commitment.to_bytes(), &proof.to_bytes())
+                .unwrap());
+        }
+        Err(err) => {
+            // The unmodified trusted setup must always load.
+            assert!(modified, "failed to load the trusted setup: {:?}", err);
+        }
+    }
+});
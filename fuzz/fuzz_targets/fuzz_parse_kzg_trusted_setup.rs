@@ -0,0 +1,30 @@
+// Run with the following command:
+// cargo fuzz run fuzz_parse_kzg_trusted_setup
+
+#![no_main]
+extern crate core;
+
+use libfuzzer_sys::fuzz_target;
+
+///////////////////////////////////////////////////////////////////////////////
+// Fuzz Target
+///////////////////////////////////////////////////////////////////////////////
+
+fuzz_target!(|data: &[u8]| {
+    // The parser only accepts text, like the trusted setup files.
+    let Ok(trusted_setup) = std::str::from_utf8(data) else {
+        return;
+    };
+
+    if let Ok(kzg_settings) = c_kzg::KzgSettings::parse_kzg_trusted_setup(trusted_setup, 0) {
+        // If the setup was accepted, it must be usable.
+        let blob = c_kzg::Blob::default();
+        let commitment = kzg_settings.blob_to_kzg_commitment(&blob).unwrap();
+        let proof = kzg_settings
+            .compute_blob_kzg_proof(&blob, &commitment.to_bytes())
+            .unwrap();
+        assert!(kzg_settings
+            .verify_blob_kzg_proof(&blob, &commitment.to_bytes(), &proof.to_bytes())
+            .unwrap());
+    }
+});
@@ -279,6 +279,75 @@ out:
     return ret;
 }
 
+/**
+ * Add the contribution of a chunk of a blob's field elements to a partially computed commitment.
+ *
+ * Calling this for every chunk of a blob, in any order, and then finalize_kzg_commitment() gives
+ * the same result as blob_to_kzg_commitment(). Field elements which are never provided are treated
+ * as zero.
+ *
+ * @param[in,out]   commitment          The partial commitment, start with the identity point
+ * @param[in]       field_elements      The chunk of field elements, length `num_field_elements`
+ * @param[in]       offset              The index of the chunk's first field element in the blob
+ * @param[in]       num_field_elements  The number of field elements in the chunk
+ * @param[in]       s                   The trusted setup
+ *
+ * @remark The caller is responsible for not providing the same field element more than once.
+ * @remark On error, `commitment` is left unchanged.
+ */
+C_KZG_RET accumulate_kzg_commitment(
+    g1_t *commitment,
+    const Bytes32 *field_elements,
+    uint64_t offset,
+    uint64_t num_field_elements,
+    const KZGSettings *s
+) {
+    C_KZG_RET ret;
+    fr_t *poly = NULL;
+    g1_t partial_commitment;
+
+    /* Ensure the chunk fits within a blob */
+    if (offset > FIELD_ELEMENTS_PER_BLOB || num_field_elements > FIELD_ELEMENTS_PER_BLOB - offset) {
+        return C_KZG_BADARGS;
+    }
+
+    /* Nothing to add for an empty chunk */
+    if (num_field_elements == 0) {
+        return C_KZG_OK;
+    }
+
+    ret = new_fr_array(&poly, num_field_elements);
+    if (ret != C_KZG_OK) goto out;
+
+    /* Convert the untrusted chunk to field elements */
+    for (size_t i = 0; i < num_field_elements; i++) {
+        ret = bytes_to_bls_field(&poly[i], &field_elements[i]);
+        if (ret != C_KZG_OK) goto out;
+    }
+
+    /* Commit to the chunk using the matching slice of the setup */
+    ret = g1_lincomb_fast(
+        &partial_commitment, &s->g1_values_lagrange_brp[offset], poly, num_field_elements
+    );
+    if (ret != C_KZG_OK) goto out;
+
+    blst_p1_add_or_double(commitment, commitment, &partial_commitment);
+
+out:
+    c_kzg_free(poly);
+    return ret;
+}
+
+/**
+ * Serialize a commitment which was computed with accumulate_kzg_commitment().
+ *
+ * @param[out]  out         The resulting commitment
+ * @param[in]   commitment  The accumulated commitment
+ */
+void finalize_kzg_commitment(KZGCommitment *out, const g1_t *commitment) {
+    bytes_from_g1(out, commitment);
+}
+
 /* Forward function declaration */
 static C_KZG_RET verify_kzg_proof_impl(
     bool *ok,
@@ -327,6 +396,42 @@ C_KZG_RET verify_kzg_proof(
     return verify_kzg_proof_impl(ok, &commitment_g1, &z_fr, &y_fr, &proof_g1, s);
 }
 
+/**
+ * Verify a KZG proof claiming that `p(z) == y`, given an already validated commitment and proof.
+ *
+ * @param[out]  ok          True if the proofs are valid, otherwise false
+ * @param[in]   commitment  The KZG commitment corresponding to poly p(x)
+ * @param[in]   z_bytes     The evaluation point
+ * @param[in]   y_bytes     The claimed evaluation result
+ * @param[in]   proof       The KZG proof
+ * @param[in]   s           The trusted setup
+ *
+ * @remark The commitment and proof must have been checked with bytes_to_kzg_commitment() and
+ * bytes_to_kzg_proof(). This allows callers to skip decompression when reusing them.
+ */
+C_KZG_RET verify_kzg_proof_g1(
+    bool *ok,
+    const g1_t *commitment,
+    const Bytes32 *z_bytes,
+    const Bytes32 *y_bytes,
+    const g1_t *proof,
+    const KZGSettings *s
+) {
+    C_KZG_RET ret;
+    fr_t z_fr, y_fr;
+
+    *ok = false;
+
+    /* Convert untrusted inputs to trusted inputs */
+    ret = bytes_to_bls_field(&z_fr, z_bytes);
+    if (ret != C_KZG_OK) return ret;
+    ret = bytes_to_bls_field(&y_fr, y_bytes);
+    if (ret != C_KZG_OK) return ret;
+
+    /* Call helper to do pairings check */
+    return verify_kzg_proof_impl(ok, commitment, &z_fr, &y_fr, proof, s);
+}
+
 /**
  * Helper function: Verify KZG proof claiming that `p(z) == y`.
  *
@@ -534,6 +639,53 @@ out:
     return ret;
 }
 
+/**
+ * Given a blob, compute its KZG commitment and the KZG proof that is used to verify the blob
+ * against that commitment.
+ *
+ * This is equivalent to calling blob_to_kzg_commitment() followed by compute_blob_kzg_proof(), but
+ * the blob is only converted to a polynomial once and the commitment is never decompressed.
+ *
+ * @param[out]  commitment_out  The resulting commitment
+ * @param[out]  proof_out       The resulting proof
+ * @param[in]   blob            A blob
+ * @param[in]   s               The trusted setup
+ */
+C_KZG_RET blob_to_kzg_commitment_and_proof(
+    KZGCommitment *commitment_out, KZGProof *proof_out, const Blob *blob, const KZGSettings *s
+) {
+    C_KZG_RET ret;
+    fr_t *poly = NULL;
+    g1_t commitment_g1;
+    fr_t evaluation_challenge_fr;
+    fr_t y;
+
+    /* Allocate space for our polynomial */
+    ret = new_fr_array(&poly, FIELD_ELEMENTS_PER_BLOB);
+    if (ret != C_KZG_OK) goto out;
+
+    /* Convert the blob to a polynomial, shared by both computations */
+    ret = blob_to_polynomial(poly, blob);
+    if (ret != C_KZG_OK) goto out;
+
+    /* Commit to the polynomial */
+    ret = poly_to_kzg_commitment(&commitment_g1, poly, s);
+    if (ret != C_KZG_OK) goto out;
+
+    /* Compute the challenge for the blob/commitment */
+    compute_challenge(&evaluation_challenge_fr, blob, &commitment_g1);
+
+    /* Call helper function to compute proof and y */
+    ret = compute_kzg_proof_impl(proof_out, &y, poly, &evaluation_challenge_fr, s);
+    if (ret != C_KZG_OK) goto out;
+
+    bytes_from_g1(commitment_out, &commitment_g1);
+
+out:
+    c_kzg_free(poly);
+    return ret;
+}
+
 /**
  * Given a blob and its proof, verify that it corresponds to the provided commitment.
  *
@@ -549,11 +701,44 @@ C_KZG_RET verify_blob_kzg_proof(
     const Bytes48 *commitment_bytes,
     const Bytes48 *proof_bytes,
     const KZGSettings *s
+) {
+    C_KZG_RET ret;
+    g1_t commitment_g1, proof_g1;
+
+    *ok = false;
+
+    /* Do conversions first to fail fast, compute_challenge is expensive */
+    ret = bytes_to_kzg_commitment(&commitment_g1, commitment_bytes);
+    if (ret != C_KZG_OK) return ret;
+    ret = bytes_to_kzg_proof(&proof_g1, proof_bytes);
+    if (ret != C_KZG_OK) return ret;
+
+    return verify_blob_kzg_proof_g1(ok, blob, &commitment_g1, &proof_g1, s);
+}
+
+/**
+ * Given a blob and its proof, verify that it corresponds to the provided commitment. This is the
+ * same as verify_blob_kzg_proof() but takes an already validated commitment and proof.
+ *
+ * @param[out]  ok          True if the proofs are valid, otherwise false
+ * @param[in]   blob        Blob to verify
+ * @param[in]   commitment  Commitment to verify
+ * @param[in]   proof       Proof used for verification
+ * @param[in]   s           The trusted setup
+ *
+ * @remark The commitment and proof must have been checked with bytes_to_kzg_commitment() and
+ * bytes_to_kzg_proof().
+ */
+C_KZG_RET verify_blob_kzg_proof_g1(
+    bool *ok,
+    const Blob *blob,
+    const g1_t *commitment,
+    const g1_t *proof,
+    const KZGSettings *s
 ) {
     C_KZG_RET ret;
     fr_t *poly = NULL;
     fr_t evaluation_challenge_fr, y_fr;
-    g1_t commitment_g1, proof_g1;
 
     *ok = false;
 
@@ -562,22 +747,18 @@ C_KZG_RET verify_blob_kzg_proof(
     if (ret != C_KZG_OK) goto out;
 
     /* Do conversions first to fail fast, compute_challenge is expensive */
-    ret = bytes_to_kzg_commitment(&commitment_g1, commitment_bytes);
-    if (ret != C_KZG_OK) goto out;
     ret = blob_to_polynomial(poly, blob);
     if (ret != C_KZG_OK) goto out;
-    ret = bytes_to_kzg_proof(&proof_g1, proof_bytes);
-    if (ret != C_KZG_OK) goto out;
 
     /* Compute challenge for the blob/commitment */
-    compute_challenge(&evaluation_challenge_fr, blob, &commitment_g1);
+    compute_challenge(&evaluation_challenge_fr, blob, commitment);
 
     /* Evaluate challenge to get y */
     ret = evaluate_polynomial_in_evaluation_form(&y_fr, poly, &evaluation_challenge_fr, s);
     if (ret != C_KZG_OK) goto out;
 
     /* Call helper to do pairings check */
-    ret = verify_kzg_proof_impl(ok, &commitment_g1, &evaluation_challenge_fr, &y_fr, &proof_g1, s);
+    ret = verify_kzg_proof_impl(ok, commitment, &evaluation_challenge_fr, &y_fr, proof, s);
     if (ret != C_KZG_OK) goto out;
 
 out:
@@ -741,6 +922,65 @@ out:
     return ret;
 }
 
+/**
+ * Helper function for the blob batch verifiers: compute the challenges and evaluations for each
+ * blob, then verify them all at once.
+ *
+ * @param[out]  ok              True if the proofs are valid, otherwise false
+ * @param[in]   blobs           Array of blobs to verify
+ * @param[in]   commitments_g1  Array of validated commitments
+ * @param[in]   proofs_g1       Array of validated proofs
+ * @param[in]   n               The number of blobs/commitments/proofs
+ * @param[in]   s               The trusted setup
+ *
+ * @remark This function only works for `n > 0`.
+ */
+static C_KZG_RET verify_blob_kzg_proof_batch_impl(
+    bool *ok,
+    const Blob *blobs,
+    const g1_t *commitments_g1,
+    const g1_t *proofs_g1,
+    size_t n,
+    const KZGSettings *s
+) {
+    C_KZG_RET ret;
+    fr_t *evaluation_challenges_fr = NULL;
+    fr_t *ys_fr = NULL;
+    fr_t *poly = NULL;
+
+    *ok = false;
+
+    ret = new_fr_array(&evaluation_challenges_fr, n);
+    if (ret != C_KZG_OK) goto out;
+    ret = new_fr_array(&ys_fr, n);
+    if (ret != C_KZG_OK) goto out;
+    ret = new_fr_array(&poly, FIELD_ELEMENTS_PER_BLOB);
+    if (ret != C_KZG_OK) goto out;
+
+    for (size_t i = 0; i < n; i++) {
+        /* Convert each blob from bytes to a poly */
+        ret = blob_to_polynomial(poly, &blobs[i]);
+        if (ret != C_KZG_OK) goto out;
+
+        compute_challenge(&evaluation_challenges_fr[i], &blobs[i], &commitments_g1[i]);
+
+        ret = evaluate_polynomial_in_evaluation_form(
+            &ys_fr[i], poly, &evaluation_challenges_fr[i], s
+        );
+        if (ret != C_KZG_OK) goto out;
+    }
+
+    ret = verify_kzg_proof_batch(
+        ok, commitments_g1, evaluation_challenges_fr, ys_fr, proofs_g1, n, s
+    );
+
+out:
+    c_kzg_free(evaluation_challenges_fr);
+    c_kzg_free(ys_fr);
+    c_kzg_free(poly);
+    return ret;
+}
+
 /**
  * Given a list of blobs and blob KZG proofs, verify that they correspond to the provided
  * commitments.
@@ -767,9 +1007,6 @@ C_KZG_RET verify_blob_kzg_proof_batch(
     C_KZG_RET ret;
     g1_t *commitments_g1 = NULL;
     g1_t *proofs_g1 = NULL;
-    fr_t *evaluation_challenges_fr = NULL;
-    fr_t *ys_fr = NULL;
-    fr_t *poly = NULL;
 
     /* Exit early if we are given zero blobs */
     if (n == 0) {
@@ -782,47 +1019,64 @@ C_KZG_RET verify_blob_kzg_proof_batch(
         return verify_blob_kzg_proof(ok, &blobs[0], &commitments_bytes[0], &proofs_bytes[0], s);
     }
 
+    *ok = false;
+
     /* We will need a bunch of arrays to store our objects... */
     ret = new_g1_array(&commitments_g1, n);
     if (ret != C_KZG_OK) goto out;
     ret = new_g1_array(&proofs_g1, n);
     if (ret != C_KZG_OK) goto out;
-    ret = new_fr_array(&evaluation_challenges_fr, n);
-    if (ret != C_KZG_OK) goto out;
-    ret = new_fr_array(&ys_fr, n);
-    if (ret != C_KZG_OK) goto out;
-    ret = new_fr_array(&poly, FIELD_ELEMENTS_PER_BLOB);
-    if (ret != C_KZG_OK) goto out;
 
     for (size_t i = 0; i < n; i++) {
-        /* Convert each commitment to a g1 point */
+        /* Convert each commitment and proof to a g1 point */
         ret = bytes_to_kzg_commitment(&commitments_g1[i], &commitments_bytes[i]);
         if (ret != C_KZG_OK) goto out;
-
-        /* Convert each blob from bytes to a poly */
-        ret = blob_to_polynomial(poly, &blobs[i]);
-        if (ret != C_KZG_OK) goto out;
-
-        compute_challenge(&evaluation_challenges_fr[i], &blobs[i], &commitments_g1[i]);
-
-        ret = evaluate_polynomial_in_evaluation_form(
-            &ys_fr[i], poly, &evaluation_challenges_fr[i], s
-        );
-        if (ret != C_KZG_OK) goto out;
-
         ret = bytes_to_kzg_proof(&proofs_g1[i], &proofs_bytes[i]);
         if (ret != C_KZG_OK) goto out;
     }
 
-    ret = verify_kzg_proof_batch(
-        ok, commitments_g1, evaluation_challenges_fr, ys_fr, proofs_g1, n, s
-    );
+    ret = verify_blob_kzg_proof_batch_impl(ok, blobs, commitments_g1, proofs_g1, n, s);
 
 out:
     c_kzg_free(commitments_g1);
     c_kzg_free(proofs_g1);
-    c_kzg_free(evaluation_challenges_fr);
-    c_kzg_free(ys_fr);
-    c_kzg_free(poly);
     return ret;
 }
+
+/**
+ * Given a list of blobs and blob KZG proofs, verify that they correspond to the provided
+ * commitments. This is the same as verify_blob_kzg_proof_batch() but takes already validated
+ * commitments and proofs.
+ *
+ * @param[out]  ok              True if the proofs are valid, otherwise false
+ * @param[in]   blobs           Array of blobs to verify
+ * @param[in]   commitments     Array of commitments to verify
+ * @param[in]   proofs          Array of proofs used for verification
+ * @param[in]   n               The number of blobs/commitments/proofs
+ * @param[in]   s               The trusted setup
+ *
+ * @remark This function accepts if called with `n==0`.
+ * @remark The commitments and proofs must have been checked with bytes_to_kzg_commitment() and
+ * bytes_to_kzg_proof().
+ */
+C_KZG_RET verify_blob_kzg_proof_batch_g1(
+    bool *ok,
+    const Blob *blobs,
+    const g1_t *commitments,
+    const g1_t *proofs,
+    uint64_t n,
+    const KZGSettings *s
+) {
+    /* Exit early if we are given zero blobs */
+    if (n == 0) {
+        *ok = true;
+        return C_KZG_OK;
+    }
+
+    /* For a single blob, just do a regular single verification */
+    if (n == 1) {
+        return verify_blob_kzg_proof_g1(ok, &blobs[0], &commitments[0], &proofs[0], s);
+    }
+
+    return verify_blob_kzg_proof_batch_impl(ok, blobs, commitments, proofs, n, s);
+}
@@ -42,6 +42,16 @@ extern "C" {
 
 C_KZG_RET blob_to_kzg_commitment(KZGCommitment *out, const Blob *blob, const KZGSettings *s);
 
+C_KZG_RET accumulate_kzg_commitment(
+    g1_t *commitment,
+    const Bytes32 *field_elements,
+    uint64_t offset,
+    uint64_t num_field_elements,
+    const KZGSettings *s
+);
+
+void finalize_kzg_commitment(KZGCommitment *out, const g1_t *commitment);
+
 C_KZG_RET compute_kzg_proof(
     KZGProof *proof_out,
     Bytes32 *y_out,
@@ -54,6 +64,10 @@ C_KZG_RET compute_blob_kzg_proof(
     KZGProof *out, const Blob *blob, const Bytes48 *commitment_bytes, const KZGSettings *s
 );
 
+C_KZG_RET blob_to_kzg_commitment_and_proof(
+    KZGCommitment *commitment_out, KZGProof *proof_out, const Blob *blob, const KZGSettings *s
+);
+
 C_KZG_RET verify_kzg_proof(
     bool *ok,
     const Bytes48 *commitment_bytes,
@@ -63,6 +77,15 @@ C_KZG_RET verify_kzg_proof(
     const KZGSettings *s
 );
 
+C_KZG_RET verify_kzg_proof_g1(
+    bool *ok,
+    const g1_t *commitment,
+    const Bytes32 *z_bytes,
+    const Bytes32 *y_bytes,
+    const g1_t *proof,
+    const KZGSettings *s
+);
+
 C_KZG_RET verify_blob_kzg_proof(
     bool *ok,
     const Blob *blob,
@@ -71,6 +94,10 @@ C_KZG_RET verify_blob_kzg_proof(
     const KZGSettings *s
 );
 
+C_KZG_RET verify_blob_kzg_proof_g1(
+    bool *ok, const Blob *blob, const g1_t *commitment, const g1_t *proof, const KZGSettings *s
+);
+
 C_KZG_RET verify_blob_kzg_proof_batch(
     bool *ok,
     const Blob *blobs,
@@ -80,6 +107,15 @@ C_KZG_RET verify_blob_kzg_proof_batch(
     const KZGSettings *s
 );
 
+C_KZG_RET verify_blob_kzg_proof_batch_g1(
+    bool *ok,
+    const Blob *blobs,
+    const g1_t *commitments,
+    const g1_t *proofs,
+    uint64_t n,
+    const KZGSettings *s
+);
+
 /* Internal function exposed for testing purposes */
 void compute_challenge(fr_t *eval_challenge_out, const Blob *blob, const g1_t *commitment);
 
@@ -34,6 +34,9 @@
 /** Length of the domain string. */
 #define DOMAIN_STR_LENGTH 16
 
+/** The largest number of cell proofs computed individually rather than with FK20. */
+#define CELL_PROOFS_DIRECT_THRESHOLD 4
+
 ////////////////////////////////////////////////////////////////////////////////////////////////////
 // Constants
 ////////////////////////////////////////////////////////////////////////////////////////////////////
@@ -145,6 +148,135 @@ out:
     return ret;
 }
 
+/* Forward function declaration */
+static void get_coset_shift_pow_for_cell(
+    fr_t *coset_factor_out, uint64_t cell_index, const KZGSettings *s
+);
+
+/**
+ * Compute the proof for a single cell directly, without FK20.
+ *
+ * The proof is a commitment to the quotient q(X) = (p(X) - I(X)) / (X^n - h_k^n), where I(X) is the
+ * interpolation polynomial of the cell and X^n - h_k^n vanishes on the cell's coset. The quotient
+ * is found via synthetic division, which discards the remainder I(X), and then committed to with
+ * a single MSM against the monomial setup.
+ *
+ * @param[out]  out             The proof for the cell
+ * @param[in]   poly_monomial   The polynomial, an array of FIELD_ELEMENTS_PER_BLOB coefficients
+ * @param[in]   cell_index      The index of the cell
+ * @param[in]   s               The trusted setup
+ */
+static C_KZG_RET compute_cell_kzg_proof_direct(
+    g1_t *out, const fr_t *poly_monomial, uint64_t cell_index, const KZGSettings *s
+) {
+    C_KZG_RET ret;
+    fr_t *quotient = NULL;
+    fr_t coset_factor_pow;
+    const size_t n = FIELD_ELEMENTS_PER_CELL;
+    const size_t quotient_len = FIELD_ELEMENTS_PER_BLOB - FIELD_ELEMENTS_PER_CELL;
+
+    ret = new_fr_array(&quotient, quotient_len);
+    if (ret != C_KZG_OK) goto out;
+
+    /* Get h_k^n, the constant term of the vanishing polynomial is its negation */
+    get_coset_shift_pow_for_cell(&coset_factor_pow, cell_index, s);
+
+    /* Divide by X^n - h_k^n, starting from the highest coefficient */
+    for (size_t i = quotient_len; i-- > 0;) {
+        quotient[i] = poly_monomial[i + n];
+        if (i + n < quotient_len) {
+            fr_t tmp;
+            blst_fr_mul(&tmp, &coset_factor_pow, &quotient[i + n]);
+            blst_fr_add(&quotient[i], &quotient[i], &tmp);
+        }
+    }
+
+    /* Commit to the quotient */
+    ret = g1_lincomb_fast(out, s->g1_values_monomial, quotient, quotient_len);
+    if (ret != C_KZG_OK) goto out;
+
+out:
+    c_kzg_free(quotient);
+    return ret;
+}
+
+/**
+ * Given a blob, compute the proofs for a selection of its cells.
+ *
+ * @param[out]  proofs          An array of proofs, length `num_cells`
+ * @param[in]   blob            The blob to get proofs for
+ * @param[in]   cell_indices    The indices of the cells to get proofs for, length `num_cells`
+ * @param[in]   num_cells       The number of proofs to compute
+ * @param[in]   s               The trusted setup
+ *
+ * @remark Up to CELL_PROOFS_DIRECT_THRESHOLD proofs are computed one at a time with a quotient
+ * commitment each. Larger selections compute every proof with FK20 and pick the requested ones.
+ * @remark Cell indices may be given in any order and may repeat.
+ */
+C_KZG_RET compute_cell_kzg_proofs(
+    KZGProof *proofs,
+    const Blob *blob,
+    const uint64_t *cell_indices,
+    uint64_t num_cells,
+    const KZGSettings *s
+) {
+    C_KZG_RET ret;
+    fr_t *poly_monomial = NULL;
+    fr_t *poly_lagrange = NULL;
+    g1_t *proofs_g1 = NULL;
+    g1_t proof_g1;
+
+    /* Check that the cell indices are valid */
+    for (size_t i = 0; i < num_cells; i++) {
+        if (cell_indices[i] >= CELLS_PER_EXT_BLOB) {
+            return C_KZG_BADARGS;
+        }
+    }
+
+    /* Allocate space fr-form arrays */
+    ret = new_fr_array(&poly_monomial, FIELD_ELEMENTS_PER_BLOB);
+    if (ret != C_KZG_OK) goto out;
+    ret = new_fr_array(&poly_lagrange, FIELD_ELEMENTS_PER_BLOB);
+    if (ret != C_KZG_OK) goto out;
+
+    /* Convert the blob to a polynomial in monomial form */
+    ret = blob_to_polynomial(poly_lagrange, blob);
+    if (ret != C_KZG_OK) goto out;
+    ret = poly_lagrange_to_monomial(poly_monomial, poly_lagrange, FIELD_ELEMENTS_PER_BLOB, s);
+    if (ret != C_KZG_OK) goto out;
+
+    if (num_cells <= CELL_PROOFS_DIRECT_THRESHOLD) {
+        for (size_t i = 0; i < num_cells; i++) {
+            ret = compute_cell_kzg_proof_direct(&proof_g1, poly_monomial, cell_indices[i], s);
+            if (ret != C_KZG_OK) goto out;
+            bytes_from_g1(&proofs[i], &proof_g1);
+        }
+    } else {
+        /* Allocate space for our proofs in g1-form */
+        ret = new_g1_array(&proofs_g1, CELLS_PER_EXT_BLOB);
+        if (ret != C_KZG_OK) goto out;
+
+        /* Compute all of the proofs */
+        ret = compute_fk20_cell_proofs(proofs_g1, poly_monomial, s);
+        if (ret != C_KZG_OK) goto out;
+
+        /* Bit-reverse the proofs */
+        ret = bit_reversal_permutation(proofs_g1, sizeof(g1_t), CELLS_PER_EXT_BLOB);
+        if (ret != C_KZG_OK) goto out;
+
+        /* Convert the requested proofs to byte-form */
+        for (size_t i = 0; i < num_cells; i++) {
+            bytes_from_g1(&proofs[i], &proofs_g1[cell_indices[i]]);
+        }
+    }
+
+out:
+    c_kzg_free(poly_monomial);
+    c_kzg_free(poly_lagrange);
+    c_kzg_free(proofs_g1);
+    return ret;
+}
+
 ////////////////////////////////////////////////////////////////////////////////////////////////////
 // Recover
 ////////////////////////////////////////////////////////////////////////////////////////////////////
@@ -203,8 +335,6 @@ C_KZG_RET recover_cells_and_kzg_proofs(
     /* Do allocations */
     ret = new_fr_array(&recovered_cells_fr, FIELD_ELEMENTS_PER_EXT_BLOB);
     if (ret != C_KZG_OK) goto out;
-    ret = new_g1_array(&recovered_proofs_g1, CELLS_PER_EXT_BLOB);
-    if (ret != C_KZG_OK) goto out;
 
     /* Initialize all cells as missing */
     for (size_t i = 0; i < FIELD_ELEMENTS_PER_EXT_BLOB; i++) {
@@ -260,6 +390,10 @@ C_KZG_RET recover_cells_and_kzg_proofs(
         );
         if (ret != C_KZG_OK) goto out;
 
+        /* Allocate space for our proofs in g1-form */
+        ret = new_g1_array(&recovered_proofs_g1, CELLS_PER_EXT_BLOB);
+        if (ret != C_KZG_OK) goto out;
+
         /* Compute the proofs, only uses the first half of the polynomial */
         ret = compute_fk20_cell_proofs(recovered_proofs_g1, recovered_cells_fr, s);
         if (ret != C_KZG_OK) goto out;
@@ -280,6 +414,83 @@ out:
     return ret;
 }
 
+/**
+ * Given some cells for a blob, get back the original blob.
+ *
+ * The first CELLS_PER_BLOB cells of the extended blob hold the blob's data in order. If they are
+ * all available they are copied directly, otherwise the missing ones are recovered first.
+ *
+ * @param[out]  blob            The original blob
+ * @param[in]   cell_indices    The cell indices for the available cells, length `num_cells`
+ * @param[in]   cells           The available cells, length `num_cells`
+ * @param[in]   num_cells       The number of available cells provided
+ * @param[in]   s               The trusted setup
+ *
+ * @remark At least CELLS_PER_BLOB cells must be provided.
+ * @remark Cell indices must be in strictly ascending order.
+ * @remark If the first CELLS_PER_BLOB cells are provided, the other cells are not checked.
+ */
+C_KZG_RET cells_to_blob(
+    Blob *blob,
+    const uint64_t *cell_indices,
+    const Cell *cells,
+    uint64_t num_cells,
+    const KZGSettings *s
+) {
+    C_KZG_RET ret;
+    fr_t tmp;
+    Cell *recovered_cells = NULL;
+
+    /* Check that there are enough cells and that the cell indices are valid */
+    if (num_cells < CELLS_PER_BLOB || num_cells > CELLS_PER_EXT_BLOB) {
+        ret = C_KZG_BADARGS;
+        goto out;
+    }
+    for (size_t i = 0; i < num_cells; i++) {
+        if (cell_indices[i] >= CELLS_PER_EXT_BLOB) {
+            ret = C_KZG_BADARGS;
+            goto out;
+        }
+        if (i > 0 && cell_indices[i] <= cell_indices[i - 1]) {
+            ret = C_KZG_BADARGS;
+            goto out;
+        }
+    }
+
+    /*
+     * With strictly ascending indices, the first half of the extended blob is available exactly
+     * when the first CELLS_PER_BLOB indices are 0 through CELLS_PER_BLOB-1.
+     */
+    if (cell_indices[CELLS_PER_BLOB - 1] == CELLS_PER_BLOB - 1) {
+        for (size_t i = 0; i < CELLS_PER_BLOB; i++) {
+            /* Make sure the cell only contains canonical field elements */
+            for (size_t j = 0; j < FIELD_ELEMENTS_PER_CELL; j++) {
+                size_t offset = j * BYTES_PER_FIELD_ELEMENT;
+                ret = bytes_to_bls_field(&tmp, (const Bytes32 *)&cells[i].bytes[offset]);
+                if (ret != C_KZG_OK) goto out;
+            }
+            memcpy(&blob->bytes[i * BYTES_PER_CELL], cells[i].bytes, BYTES_PER_CELL);
+        }
+        ret = C_KZG_OK;
+        goto out;
+    }
+
+    /* Recover the missing cells, we do not need the proofs */
+    ret = c_kzg_calloc((void **)&recovered_cells, CELLS_PER_EXT_BLOB, sizeof(Cell));
+    if (ret != C_KZG_OK) goto out;
+    ret = recover_cells_and_kzg_proofs(recovered_cells, NULL, cell_indices, cells, num_cells, s);
+    if (ret != C_KZG_OK) goto out;
+
+    /* Copy the first half of the extended blob */
+    for (size_t i = 0; i < CELLS_PER_BLOB; i++) {
+        memcpy(&blob->bytes[i * BYTES_PER_CELL], recovered_cells[i].bytes, BYTES_PER_CELL);
+    }
+
+out:
+    c_kzg_free(recovered_cells);
+    return ret;
+}
+
 ////////////////////////////////////////////////////////////////////////////////////////////////////
 // Verify
 ////////////////////////////////////////////////////////////////////////////////////////////////////
@@ -462,7 +673,7 @@ out:
  * Compute the sum of the commitments weighted by the powers of r.
  *
  * @param[out]  sum_of_commitments_out  The resulting G1 sum of the commitments
- * @param[in]   unique_commitments      Array of unique commitments, length `num_commitments`
+ * @param[in]   commitments_g1          Array of unique commitments, length `num_commitments`
  * @param[in]   commitment_indices      Indices mapping to unique commitments, length `num_cells`
  * @param[in]   r_powers                Array of powers of r used for weighting, length `num_cells`
  * @param[in]   num_commitments         The number of unique commitments
@@ -470,26 +681,19 @@ out:
  */
 static C_KZG_RET compute_weighted_sum_of_commitments(
     g1_t *sum_of_commitments_out,
-    const Bytes48 *unique_commitments,
+    const g1_t *commitments_g1,
     const uint64_t *commitment_indices,
     const fr_t *r_powers,
     size_t num_commitments,
     uint64_t num_cells
 ) {
     C_KZG_RET ret;
-    g1_t *commitments_g1 = NULL;
     fr_t *commitment_weights = NULL;
 
     ret = new_fr_array(&commitment_weights, num_commitments);
     if (ret != C_KZG_OK) goto out;
-    ret = new_g1_array(&commitments_g1, num_commitments);
-    if (ret != C_KZG_OK) goto out;
 
     for (size_t i = 0; i < num_commitments; i++) {
-        /* Convert & validate commitment */
-        ret = bytes_to_kzg_commitment(&commitments_g1[i], &unique_commitments[i]);
-        if (ret != C_KZG_OK) goto out;
-
         /* Initialize the weight to zero */
         commitment_weights[i] = FR_ZERO;
     }
@@ -511,7 +715,6 @@ static C_KZG_RET compute_weighted_sum_of_commitments(
 
 out:
     c_kzg_free(commitment_weights);
-    c_kzg_free(commitments_g1);
     return ret;
 }
 
@@ -789,22 +992,28 @@ out:
 }
 
 /**
- * Given some cells, verify that their proofs are valid.
+ * Helper function for the cell batch verifiers: actually perform the verification.
  *
  * @param[out]  ok                  True if the proofs are valid
  * @param[in]   commitments_bytes   The commitments for the cells, length `num_cells`
+ * @param[in]   commitments_g1      The validated commitments, length `num_cells`, or NULL
  * @param[in]   cell_indices        The indices for the cells, length `num_cells`
  * @param[in]   cells               The cells to check, length `num_cells`
  * @param[in]   proofs_bytes        The proofs for the cells, length `num_cells`
+ * @param[in]   proofs_g1           The validated proofs, length `num_cells`, or NULL
  * @param[in]   num_cells           The number of cells provided
  * @param[in]   s                   The trusted setup
+ *
+ * @remark If `commitments_g1` or `proofs_g1` is NULL, the points are parsed from the bytes.
  */
-C_KZG_RET verify_cell_kzg_proof_batch(
+static C_KZG_RET verify_cell_kzg_proof_batch_impl(
     bool *ok,
     const Bytes48 *commitments_bytes,
+    const g1_t *commitments_g1,
     const uint64_t *cell_indices,
     const Cell *cells,
     const Bytes48 *proofs_bytes,
+    const g1_t *proofs_g1,
     uint64_t num_cells,
     const KZGSettings *s
 ) {
@@ -821,7 +1030,8 @@ C_KZG_RET verify_cell_kzg_proof_batch(
     Bytes48 *unique_commitments = NULL;
     uint64_t *commitment_indices = NULL;
     fr_t *r_powers = NULL;
-    g1_t *proofs_g1 = NULL;
+    g1_t *unique_commitments_g1 = NULL;
+    g1_t *parsed_proofs_g1 = NULL;
 
     *ok = false;
 
@@ -864,8 +1074,12 @@ C_KZG_RET verify_cell_kzg_proof_batch(
 
     ret = new_fr_array(&r_powers, num_cells);
     if (ret != C_KZG_OK) goto out;
-    ret = new_g1_array(&proofs_g1, num_cells);
+    ret = new_g1_array(&unique_commitments_g1, num_commitments);
     if (ret != C_KZG_OK) goto out;
+    if (proofs_g1 == NULL) {
+        ret = new_g1_array(&parsed_proofs_g1, num_cells);
+        if (ret != C_KZG_OK) goto out;
+    }
 
     ////////////////////////////////////////////////////////////////////////////////////////////////
     // Compute powers of r, and extract KZG proofs out of input bytes
@@ -891,9 +1105,28 @@ C_KZG_RET verify_cell_kzg_proof_batch(
     compute_powers(r_powers, &r, num_cells);
 
     /* There should be a proof for each cell */
-    for (size_t i = 0; i < num_cells; i++) {
-        ret = bytes_to_kzg_proof(&proofs_g1[i], &proofs_bytes[i]);
-        if (ret != C_KZG_OK) goto out;
+    if (proofs_g1 == NULL) {
+        for (size_t i = 0; i < num_cells; i++) {
+            ret = bytes_to_kzg_proof(&parsed_proofs_g1[i], &proofs_bytes[i]);
+            if (ret != C_KZG_OK) goto out;
+        }
+        proofs_g1 = parsed_proofs_g1;
+    }
+
+    /* Get the point for each unique commitment */
+    if (commitments_g1 == NULL) {
+        for (size_t i = 0; i < num_commitments; i++) {
+            ret = bytes_to_kzg_commitment(&unique_commitments_g1[i], &unique_commitments[i]);
+            if (ret != C_KZG_OK) goto out;
+        }
+    } else {
+        /* Unique commitments are numbered in order of their first appearance */
+        for (size_t i = 0, j = 0; i < num_cells && j < num_commitments; i++) {
+            if (commitment_indices[i] == j) {
+                unique_commitments_g1[j] = commitments_g1[i];
+                j++;
+            }
+        }
     }
 
     ////////////////////////////////////////////////////////////////////////////////////////////////
@@ -908,7 +1141,12 @@ C_KZG_RET verify_cell_kzg_proof_batch(
     ////////////////////////////////////////////////////////////////////////////////////////////////
 
     ret = compute_weighted_sum_of_commitments(
-        &final_g1_sum, unique_commitments, commitment_indices, r_powers, num_commitments, num_cells
+        &final_g1_sum,
+        unique_commitments_g1,
+        commitment_indices,
+        r_powers,
+        num_commitments,
+        num_cells
     );
     if (ret != C_KZG_OK) goto out;
 
@@ -947,6 +1185,66 @@ out:
     c_kzg_free(unique_commitments);
     c_kzg_free(commitment_indices);
     c_kzg_free(r_powers);
-    c_kzg_free(proofs_g1);
+    c_kzg_free(unique_commitments_g1);
+    c_kzg_free(parsed_proofs_g1);
     return ret;
 }
+
+/**
+ * Given some cells, verify that their proofs are valid.
+ *
+ * @param[out]  ok                  True if the proofs are valid
+ * @param[in]   commitments_bytes   The commitments for the cells, length `num_cells`
+ * @param[in]   cell_indices        The indices for the cells, length `num_cells`
+ * @param[in]   cells               The cells to check, length `num_cells`
+ * @param[in]   proofs_bytes        The proofs for the cells, length `num_cells`
+ * @param[in]   num_cells           The number of cells provided
+ * @param[in]   s                   The trusted setup
+ */
+C_KZG_RET verify_cell_kzg_proof_batch(
+    bool *ok,
+    const Bytes48 *commitments_bytes,
+    const uint64_t *cell_indices,
+    const Cell *cells,
+    const Bytes48 *proofs_bytes,
+    uint64_t num_cells,
+    const KZGSettings *s
+) {
+    return verify_cell_kzg_proof_batch_impl(
+        ok, commitments_bytes, NULL, cell_indices, cells, proofs_bytes, NULL, num_cells, s
+    );
+}
+
+/**
+ * Given some cells, verify that their proofs are valid. This is the same as
+ * verify_cell_kzg_proof_batch() but takes already validated commitments and proofs, so they are
+ * not decompressed again.
+ *
+ * @param[out]  ok                  True if the proofs are valid
+ * @param[in]   commitments_bytes   The commitments for the cells, length `num_cells`
+ * @param[in]   commitments         The validated commitments for the cells, length `num_cells`
+ * @param[in]   cell_indices        The indices for the cells, length `num_cells`
+ * @param[in]   cells               The cells to check, length `num_cells`
+ * @param[in]   proofs_bytes        The proofs for the cells, length `num_cells`
+ * @param[in]   proofs              The validated proofs for the cells, length `num_cells`
+ * @param[in]   num_cells           The number of cells provided
+ * @param[in]   s                   The trusted setup
+ *
+ * @remark The bytes are still needed to derive the challenge. Each point must be the result of
+ * bytes_to_kzg_commitment() or bytes_to_kzg_proof() on the corresponding bytes.
+ */
+C_KZG_RET verify_cell_kzg_proof_batch_g1(
+    bool *ok,
+    const Bytes48 *commitments_bytes,
+    const g1_t *commitments,
+    const uint64_t *cell_indices,
+    const Cell *cells,
+    const Bytes48 *proofs_bytes,
+    const g1_t *proofs,
+    uint64_t num_cells,
+    const KZGSettings *s
+) {
+    return verify_cell_kzg_proof_batch_impl(
+        ok, commitments_bytes, commitments, cell_indices, cells, proofs_bytes, proofs, num_cells, s
+    );
+}
@@ -36,6 +36,14 @@ C_KZG_RET compute_cells_and_kzg_proofs(
     Cell *cells, KZGProof *proofs, const Blob *blob, const KZGSettings *s
 );
 
+C_KZG_RET compute_cell_kzg_proofs(
+    KZGProof *proofs,
+    const Blob *blob,
+    const uint64_t *cell_indices,
+    uint64_t num_cells,
+    const KZGSettings *s
+);
+
 C_KZG_RET recover_cells_and_kzg_proofs(
     Cell *recovered_cells,
     KZGProof *recovered_proofs,
@@ -45,6 +53,14 @@ C_KZG_RET recover_cells_and_kzg_proofs(
     const KZGSettings *s
 );
 
+C_KZG_RET cells_to_blob(
+    Blob *blob,
+    const uint64_t *cell_indices,
+    const Cell *cells,
+    uint64_t num_cells,
+    const KZGSettings *s
+);
+
 C_KZG_RET verify_cell_kzg_proof_batch(
     bool *ok,
     const Bytes48 *commitments_bytes,
@@ -55,6 +71,18 @@ C_KZG_RET verify_cell_kzg_proof_batch(
     const KZGSettings *s
 );
 
+C_KZG_RET verify_cell_kzg_proof_batch_g1(
+    bool *ok,
+    const Bytes48 *commitments_bytes,
+    const g1_t *commitments,
+    const uint64_t *cell_indices,
+    const Cell *cells,
+    const Bytes48 *proofs_bytes,
+    const g1_t *proofs,
+    uint64_t num_cells,
+    const KZGSettings *s
+);
+
 /* Internal function exposed for testing purposes */
 C_KZG_RET compute_verify_cell_kzg_proof_batch_challenge(
     fr_t *challenge_out,
@@ -20,6 +20,7 @@
 #include "common/utils.h"
 #include "eip7594/cell.h"
 #include "eip7594/fft.h"
+#include "eip7594/poly.h"
 
 #include <assert.h> /* For assert */
 #include <stdlib.h> /* For NULL */
@@ -183,6 +184,81 @@ static bool is_in_array(const uint64_t *arr, size_t arr_size, uint64_t value) {
     return false;
 }
 
+/**
+ * Check whether all of the cells in one half of the extended blob are available.
+ *
+ * @param[in]   cells       An array of size FIELD_ELEMENTS_PER_EXT_BLOB with the cells
+ * @param[in]   second_half Whether to check the second half rather than the first
+ *
+ * @return True if none of the cells in that half are missing, otherwise false.
+ */
+static bool is_half_available(const fr_t *cells, bool second_half) {
+    size_t first_cell = second_half ? CELLS_PER_BLOB : 0;
+    for (size_t i = first_cell; i < first_cell + CELLS_PER_BLOB; i++) {
+        /* Missing cells are entirely FR_NULL, so checking the first field element is enough */
+        if (fr_is_null(&cells[i * FIELD_ELEMENTS_PER_CELL])) {
+            return false;
+        }
+    }
+    return true;
+}
+
+/**
+ * Given one complete half of the extended blob, return all of the cells.
+ *
+ * In bit-reversed order, the first half of the extended blob holds the evaluations over the
+ * subgroup of size FIELD_ELEMENTS_PER_BLOB, and the second half holds the evaluations over that
+ * subgroup's coset shifted by the primitive root of unity. Either determines the polynomial with
+ * a single inverse FFT, after which the polynomial is extended with a forward FFT.
+ *
+ * @param[out]  reconstructed_data_out  Array of size FIELD_ELEMENTS_PER_EXT_BLOB to recover cells
+ * @param[in]   cells                   An array of size FIELD_ELEMENTS_PER_EXT_BLOB with the cells
+ * @param[in]   second_half             Whether to use the second half rather than the first
+ * @param[in]   s                       The trusted setup
+ *
+ * @remark `reconstructed_data_out` and `cells` can point to the same memory.
+ * @remark The other half of `cells` is ignored.
+ */
+static C_KZG_RET recover_cells_from_half(
+    fr_t *reconstructed_data_out, const fr_t *cells, bool second_half, const KZGSettings *s
+) {
+    C_KZG_RET ret;
+    fr_t *poly = NULL;
+    const fr_t *half = second_half ? &cells[FIELD_ELEMENTS_PER_BLOB] : cells;
+
+    ret = new_fr_array(&poly, FIELD_ELEMENTS_PER_EXT_BLOB);
+    if (ret != C_KZG_OK) goto out;
+
+    /* Get the coefficients of P(x), or of P(wx) for the second half */
+    ret = poly_lagrange_to_monomial(poly, half, FIELD_ELEMENTS_PER_BLOB, s);
+    if (ret != C_KZG_OK) goto out;
+
+    if (second_half) {
+        /* Undo the shift by w, using w^-1 which is the reflected root */
+        const fr_t *inv_shift_factor = &s->roots_of_unity[FIELD_ELEMENTS_PER_EXT_BLOB - 1];
+        shift_poly(poly, FIELD_ELEMENTS_PER_BLOB, inv_shift_factor);
+    }
+
+    /* The polynomial has degree less than FIELD_ELEMENTS_PER_BLOB */
+    for (size_t i = FIELD_ELEMENTS_PER_BLOB; i < FIELD_ELEMENTS_PER_EXT_BLOB; i++) {
+        poly[i] = FR_ZERO;
+    }
+
+    /* Evaluate the polynomial over the extended domain */
+    ret = fr_fft(reconstructed_data_out, poly, FIELD_ELEMENTS_PER_EXT_BLOB, s);
+    if (ret != C_KZG_OK) goto out;
+
+    /* Bit-reverse the recovered data points */
+    ret = bit_reversal_permutation(
+        reconstructed_data_out, sizeof(fr_t), FIELD_ELEMENTS_PER_EXT_BLOB
+    );
+    if (ret != C_KZG_OK) goto out;
+
+out:
+    c_kzg_free(poly);
+    return ret;
+}
+
 /**
  * Given a set of cells with up to half the entries missing, return the reconstructed
  * original. Assumes that the inverse FFT of the original data has the upper half of its values
@@ -197,6 +273,10 @@ static bool is_in_array(const uint64_t *arr, size_t arr_size, uint64_t value) {
  * @remark `reconstructed_data_out` and `cells` can point to the same memory.
  * @remark The array `cells` must be in the correct order (according to cell_indices).
  * @remark Missing cells in `cells` should be equal to FR_NULL.
+ * @remark If either half of the extended blob is complete, the cells are re-extended from it with
+ * an inverse and a forward FFT instead. This covers any set of cells which contains a whole half,
+ * the cells outside of that half being ignored. Every other pattern, such as alternating cells,
+ * takes the general erasure-decoding path.
  */
 C_KZG_RET recover_cells(
     fr_t *reconstructed_data_out,
@@ -216,6 +296,14 @@ C_KZG_RET recover_cells(
     fr_t *reconstructed_poly_coeff = NULL;
     fr_t *cells_brp = NULL;
 
+    /* Fast path: re-extend from a complete half, ignoring any cells from the other half */
+    if (is_half_available(cells, false)) {
+        return recover_cells_from_half(reconstructed_data_out, cells, false, s);
+    }
+    if (is_half_available(cells, true)) {
+        return recover_cells_from_half(reconstructed_data_out, cells, true, s);
+    }
+
     /* Allocate space for arrays */
     ret = c_kzg_calloc(
         (void **)&missing_cell_indices, FIELD_ELEMENTS_PER_EXT_BLOB, sizeof(uint64_t)
@@ -529,6 +529,66 @@ static void test_blob_to_kzg_commitment__succeeds_expected_commitment(void) {
     ASSERT_EQUALS(diff, 0);
 }
 
+////////////////////////////////////////////////////////////////////////////////////////////////////
+// Tests for accumulate_kzg_commitment
+////////////////////////////////////////////////////////////////////////////////////////////////////
+
+static void test_accumulate_kzg_commitment__succeeds_matches_blob_commitment(void) {
+    C_KZG_RET ret;
+    KZGCommitment expected, c;
+    Blob blob;
+    g1_t commitment = G1_IDENTITY;
+    const Bytes32 *field_elements = (const Bytes32 *)blob.bytes;
+    int diff;
+
+    get_rand_blob(&blob);
+    ret = blob_to_kzg_commitment(&expected, &blob, &s);
+    ASSERT_EQUALS(ret, C_KZG_OK);
+
+    /* Provide the blob in uneven chunks, out of order */
+    ret = accumulate_kzg_commitment(&commitment, &field_elements[1000], 1000, 3096, &s);
+    ASSERT_EQUALS(ret, C_KZG_OK);
+    ret = accumulate_kzg_commitment(&commitment, &field_elements[1], 1, 999, &s);
+    ASSERT_EQUALS(ret, C_KZG_OK);
+    ret = accumulate_kzg_commitment(&commitment, &field_elements[0], 0, 1, &s);
+    ASSERT_EQUALS(ret, C_KZG_OK);
+
+    finalize_kzg_commitment(&c, &commitment);
+    diff = memcmp(c.bytes, expected.bytes, BYTES_PER_COMMITMENT);
+    ASSERT_EQUALS(diff, 0);
+}
+
+static void test_accumulate_kzg_commitment__fails_chunk_out_of_range(void) {
+    C_KZG_RET ret;
+    Blob blob;
+    g1_t commitment = G1_IDENTITY;
+    const Bytes32 *field_elements = (const Bytes32 *)blob.bytes;
+
+    get_rand_blob(&blob);
+    ret = accumulate_kzg_commitment(&commitment, field_elements, 1, FIELD_ELEMENTS_PER_BLOB, &s);
+    ASSERT_EQUALS(ret, C_KZG_BADARGS);
+    ret = accumulate_kzg_commitment(
+        &commitment, field_elements, FIELD_ELEMENTS_PER_BLOB + 1, 0, &s
+    );
+    ASSERT_EQUALS(ret, C_KZG_BADARGS);
+    ASSERT("commitment is unchanged", blst_p1_is_inf(&commitment));
+}
+
+static void test_accumulate_kzg_commitment__fails_x_equal_to_modulus(void) {
+    C_KZG_RET ret;
+    Bytes32 field_elements[2];
+    g1_t commitment = G1_IDENTITY;
+
+    get_rand_field_element(&field_elements[0]);
+    bytes32_from_hex(
+        &field_elements[1], "73eda753299d7d483339d80809a1d80553bda402fffe5bfeffffffff00000001"
+    );
+
+    ret = accumulate_kzg_commitment(&commitment, field_elements, 0, 2, &s);
+    ASSERT_EQUALS(ret, C_KZG_BADARGS);
+    ASSERT("commitment is unchanged", blst_p1_is_inf(&commitment));
+}
+
 ////////////////////////////////////////////////////////////////////////////////////////////////////
 // Tests for validate_kzg_g1
 ////////////////////////////////////////////////////////////////////////////////////////////////////
@@ -1467,6 +1527,55 @@ static void test_compute_and_verify_blob_kzg_proof__fails_invalid_blob(void) {
     ASSERT_EQUALS(ret, C_KZG_BADARGS);
 }
 
+////////////////////////////////////////////////////////////////////////////////////////////////////
+// Tests for blob_to_kzg_commitment_and_proof
+////////////////////////////////////////////////////////////////////////////////////////////////////
+
+static void test_blob_to_kzg_commitment_and_proof__succeeds_matches_separate_calls(void) {
+    C_KZG_RET ret;
+    KZGCommitment c, expected_c;
+    KZGProof proof, expected_proof;
+    Blob blob;
+    bool ok;
+
+    /* Compute the commitment and proof together */
+    get_rand_blob(&blob);
+    ret = blob_to_kzg_commitment_and_proof(&c, &proof, &blob, &s);
+    ASSERT_EQUALS(ret, C_KZG_OK);
+
+    /* Compute the commitment and proof separately */
+    ret = blob_to_kzg_commitment(&expected_c, &blob, &s);
+    ASSERT_EQUALS(ret, C_KZG_OK);
+    ret = compute_blob_kzg_proof(&expected_proof, &blob, &expected_c, &s);
+    ASSERT_EQUALS(ret, C_KZG_OK);
+
+    /* They should be the same */
+    ASSERT("commitments match", memcmp(&c, &expected_c, sizeof(KZGCommitment)) == 0);
+    ASSERT("proofs match", memcmp(&proof, &expected_proof, sizeof(KZGProof)) == 0);
+
+    /* And the proof should verify */
+    ret = verify_blob_kzg_proof(&ok, &blob, &c, &proof, &s);
+    ASSERT_EQUALS(ret, C_KZG_OK);
+    ASSERT_EQUALS(ok, true);
+}
+
+static void test_blob_to_kzg_commitment_and_proof__fails_invalid_blob(void) {
+    C_KZG_RET ret;
+    KZGCommitment c;
+    KZGProof proof;
+    Bytes32 field_element;
+    Blob blob;
+
+    bytes32_from_hex(
+        &field_element, "73eda753299d7d483339d80809a1d80553bda402fffe5bfeffffffff00000001"
+    );
+    memset(&blob, 0, sizeof(blob));
+    memcpy(blob.bytes, field_element.bytes, BYTES_PER_FIELD_ELEMENT);
+
+    ret = blob_to_kzg_commitment_and_proof(&c, &proof, &blob, &s);
+    ASSERT_EQUALS(ret, C_KZG_BADARGS);
+}
+
 ////////////////////////////////////////////////////////////////////////////////////////////////////
 // Tests for verify_kzg_proof_batch
 ////////////////////////////////////////////////////////////////////////////////////////////////////
@@ -1613,6 +1722,56 @@ static void test_verify_kzg_proof_batch__fails_invalid_blob(void) {
     ASSERT_EQUALS(ret, C_KZG_BADARGS);
 }
 
+static void test_verify_kzg_proof_batch__succeeds_with_g1_points(void) {
+    C_KZG_RET ret;
+    const size_t n_cells = 4;
+    Bytes48 proofs[n_cells];
+    KZGCommitment commitments[n_cells];
+    g1_t commitments_g1[n_cells], proofs_g1[n_cells];
+    Blob blobs[n_cells];
+    Bytes32 z, y;
+    KZGProof proof;
+    bool ok;
+
+    /* Some preparation */
+    for (size_t i = 0; i < n_cells; i++) {
+        get_rand_blob(&blobs[i]);
+        ret = blob_to_kzg_commitment(&commitments[i], &blobs[i], &s);
+        ASSERT_EQUALS(ret, C_KZG_OK);
+        ret = compute_blob_kzg_proof(&proofs[i], &blobs[i], &commitments[i], &s);
+        ASSERT_EQUALS(ret, C_KZG_OK);
+        ret = bytes_to_kzg_commitment(&commitments_g1[i], &commitments[i]);
+        ASSERT_EQUALS(ret, C_KZG_OK);
+        ret = bytes_to_kzg_proof(&proofs_g1[i], &proofs[i]);
+        ASSERT_EQUALS(ret, C_KZG_OK);
+    }
+
+    /* The point-based verifiers should accept the same inputs */
+    for (size_t count = 0; count <= n_cells; count++) {
+        ret = verify_blob_kzg_proof_batch_g1(&ok, blobs, commitments_g1, proofs_g1, count, &s);
+        ASSERT_EQUALS(ret, C_KZG_OK);
+        ASSERT_EQUALS(ok, true);
+    }
+    ret = verify_blob_kzg_proof_g1(&ok, &blobs[0], &commitments_g1[0], &proofs_g1[0], &s);
+    ASSERT_EQUALS(ret, C_KZG_OK);
+    ASSERT_EQUALS(ok, true);
+
+    /* Check a proof for a random point */
+    get_rand_field_element(&z);
+    ret = compute_kzg_proof(&proof, &y, &blobs[0], &z, &s);
+    ASSERT_EQUALS(ret, C_KZG_OK);
+    ret = bytes_to_kzg_proof(&proofs_g1[0], &proof);
+    ASSERT_EQUALS(ret, C_KZG_OK);
+    ret = verify_kzg_proof_g1(&ok, &commitments_g1[0], &z, &y, &proofs_g1[0], &s);
+    ASSERT_EQUALS(ret, C_KZG_OK);
+    ASSERT_EQUALS(ok, true);
+
+    /* And reject mismatched proofs */
+    ret = verify_blob_kzg_proof_batch_g1(&ok, blobs, commitments_g1, proofs_g1, n_cells, &s);
+    ASSERT_EQUALS(ret, C_KZG_OK);
+    ASSERT_EQUALS(ok, false);
+}
+
 ////////////////////////////////////////////////////////////////////////////////////////////////////
 // Tests for expand_root_of_unity
 ////////////////////////////////////////////////////////////////////////////////////////////////////
@@ -1890,6 +2049,82 @@ static void test_shift_factors__succeeds(void) {
     }
 }
 
+////////////////////////////////////////////////////////////////////////////////////////////////////
+// Tests for compute_cell_kzg_proofs
+////////////////////////////////////////////////////////////////////////////////////////////////////
+
+static void test_compute_cell_kzg_proofs__succeeds_few_cells(void) {
+    C_KZG_RET ret;
+    Blob blob;
+    const size_t num_cells = 3;
+    uint64_t cell_indices[] = {CELLS_PER_EXT_BLOB - 1, 5, 5};
+    Cell cells[CELLS_PER_EXT_BLOB];
+    KZGProof proofs[CELLS_PER_EXT_BLOB];
+    KZGProof some_proofs[num_cells];
+    int diff;
+
+    /* Get a random blob */
+    get_rand_blob(&blob);
+
+    /* Get all of the proofs */
+    ret = compute_cells_and_kzg_proofs(cells, proofs, &blob, &s);
+    ASSERT_EQUALS(ret, C_KZG_OK);
+
+    /* Get the proofs for some cells, these are computed individually */
+    ret = compute_cell_kzg_proofs(some_proofs, &blob, cell_indices, num_cells, &s);
+    ASSERT_EQUALS(ret, C_KZG_OK);
+
+    /* Check that the proofs match */
+    for (size_t i = 0; i < num_cells; i++) {
+        diff = memcmp(&some_proofs[i], &proofs[cell_indices[i]], sizeof(KZGProof));
+        ASSERT_EQUALS(diff, 0);
+    }
+}
+
+static void test_compute_cell_kzg_proofs__succeeds_many_cells(void) {
+    C_KZG_RET ret;
+    Blob blob;
+    const size_t num_cells = CELLS_PER_EXT_BLOB / 4;
+    uint64_t cell_indices[num_cells];
+    Cell cells[CELLS_PER_EXT_BLOB];
+    KZGProof proofs[CELLS_PER_EXT_BLOB];
+    KZGProof some_proofs[num_cells];
+    int diff;
+
+    /* Get a random blob */
+    get_rand_blob(&blob);
+
+    /* Get all of the proofs */
+    ret = compute_cells_and_kzg_proofs(cells, proofs, &blob, &s);
+    ASSERT_EQUALS(ret, C_KZG_OK);
+
+    /* Select every fourth cell in descending order */
+    for (size_t i = 0; i < num_cells; i++) {
+        cell_indices[i] = CELLS_PER_EXT_BLOB - 1 - i * 4;
+    }
+
+    /* Get the proofs for those cells, these are computed with FK20 */
+    ret = compute_cell_kzg_proofs(some_proofs, &blob, cell_indices, num_cells, &s);
+    ASSERT_EQUALS(ret, C_KZG_OK);
+
+    /* Check that the proofs match */
+    for (size_t i = 0; i < num_cells; i++) {
+        diff = memcmp(&some_proofs[i], &proofs[cell_indices[i]], sizeof(KZGProof));
+        ASSERT_EQUALS(diff, 0);
+    }
+}
+
+static void test_compute_cell_kzg_proofs__fails_invalid_cell_index(void) {
+    C_KZG_RET ret;
+    Blob blob;
+    uint64_t cell_indices[] = {0, CELLS_PER_EXT_BLOB};
+    KZGProof proofs[2];
+
+    get_rand_blob(&blob);
+    ret = compute_cell_kzg_proofs(proofs, &blob, cell_indices, 2, &s);
+    ASSERT_EQUALS(ret, C_KZG_BADARGS);
+}
+
 ////////////////////////////////////////////////////////////////////////////////////////////////////
 // Tests for recover_cells_and_kzg_proofs
 ////////////////////////////////////////////////////////////////////////////////////////////////////
@@ -1934,6 +2169,147 @@ static void test_recover_cells_and_kzg_proofs__succeeds_random_blob(void) {
     }
 }
 
+static void test_recover_cells_and_kzg_proofs__succeeds_half_available(void) {
+    C_KZG_RET ret;
+    Blob blob;
+    const size_t num_partial_cells = CELLS_PER_BLOB + 1;
+    uint64_t cell_indices[num_partial_cells];
+    Cell cells[CELLS_PER_EXT_BLOB];
+    Cell partial_cells[num_partial_cells];
+    Cell recovered_cells[CELLS_PER_EXT_BLOB];
+    KZGProof proofs[CELLS_PER_EXT_BLOB];
+    KZGProof recovered_proofs[CELLS_PER_EXT_BLOB];
+    int diff;
+
+    /* Get a random blob */
+    get_rand_blob(&blob);
+
+    /* Get the cells and proofs */
+    ret = compute_cells_and_kzg_proofs(cells, proofs, &blob, &s);
+    ASSERT_EQUALS(ret, C_KZG_OK);
+
+    /* Try with the first half plus one cell, then with one cell plus the second half */
+    for (size_t half = 0; half < 2; half++) {
+        for (size_t i = 0; i < num_partial_cells; i++) {
+            cell_indices[i] = half == 0 ? i : CELLS_PER_BLOB - 1 + i;
+            memcpy(&partial_cells[i], &cells[cell_indices[i]], sizeof(Cell));
+        }
+
+        ret = recover_cells_and_kzg_proofs(
+            recovered_cells, recovered_proofs, cell_indices, partial_cells, num_partial_cells, &s
+        );
+        ASSERT_EQUALS(ret, C_KZG_OK);
+
+        /* Check that all of the cells match */
+        for (size_t i = 0; i < CELLS_PER_EXT_BLOB; i++) {
+            diff = memcmp(&cells[i], &recovered_cells[i], sizeof(Cell));
+            ASSERT_EQUALS(diff, 0);
+            diff = memcmp(&proofs[i], &recovered_proofs[i], sizeof(KZGProof));
+            ASSERT_EQUALS(diff, 0);
+        }
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////
+// Tests for cells_to_blob
+////////////////////////////////////////////////////////////////////////////////////////////////////
+
+static void test_cells_to_blob__succeeds_first_half(void) {
+    C_KZG_RET ret;
+    Blob blob, result;
+    const size_t num_partial_cells = CELLS_PER_BLOB + 1;
+    uint64_t cell_indices[num_partial_cells];
+    Cell cells[CELLS_PER_EXT_BLOB];
+    Cell partial_cells[num_partial_cells];
+    int diff;
+
+    /* Get a random blob and its cells */
+    get_rand_blob(&blob);
+    ret = compute_cells_and_kzg_proofs(cells, NULL, &blob, &s);
+    ASSERT_EQUALS(ret, C_KZG_OK);
+
+    /* Keep the first half of the cells and the last cell */
+    for (size_t i = 0; i < num_partial_cells; i++) {
+        cell_indices[i] = i < CELLS_PER_BLOB ? i : CELLS_PER_EXT_BLOB - 1;
+        memcpy(&partial_cells[i], &cells[cell_indices[i]], sizeof(Cell));
+    }
+
+    /* Get the blob back */
+    ret = cells_to_blob(&result, cell_indices, partial_cells, num_partial_cells, &s);
+    ASSERT_EQUALS(ret, C_KZG_OK);
+    diff = memcmp(&blob, &result, sizeof(Blob));
+    ASSERT_EQUALS(diff, 0);
+}
+
+static void test_cells_to_blob__succeeds_needs_recovery(void) {
+    C_KZG_RET ret;
+    Blob blob, result;
+    const size_t num_partial_cells = CELLS_PER_BLOB;
+    uint64_t cell_indices[num_partial_cells];
+    Cell cells[CELLS_PER_EXT_BLOB];
+    Cell partial_cells[num_partial_cells];
+    int diff;
+
+    /* Get a random blob and its cells */
+    get_rand_blob(&blob);
+    ret = compute_cells_and_kzg_proofs(cells, NULL, &blob, &s);
+    ASSERT_EQUALS(ret, C_KZG_OK);
+
+    /* Keep the odd cells only */
+    for (size_t i = 0; i < num_partial_cells; i++) {
+        cell_indices[i] = i * 2 + 1;
+        memcpy(&partial_cells[i], &cells[cell_indices[i]], sizeof(Cell));
+    }
+
+    /* Get the blob back */
+    ret = cells_to_blob(&result, cell_indices, partial_cells, num_partial_cells, &s);
+    ASSERT_EQUALS(ret, C_KZG_OK);
+    diff = memcmp(&blob, &result, sizeof(Blob));
+    ASSERT_EQUALS(diff, 0);
+}
+
+static void test_cells_to_blob__fails_not_enough_cells(void) {
+    C_KZG_RET ret;
+    Blob blob, result;
+    const size_t num_partial_cells = CELLS_PER_BLOB - 1;
+    uint64_t cell_indices[num_partial_cells];
+    Cell cells[CELLS_PER_EXT_BLOB];
+
+    /* Get a random blob and its cells */
+    get_rand_blob(&blob);
+    ret = compute_cells_and_kzg_proofs(cells, NULL, &blob, &s);
+    ASSERT_EQUALS(ret, C_KZG_OK);
+
+    for (size_t i = 0; i < num_partial_cells; i++) {
+        cell_indices[i] = i;
+    }
+
+    ret = cells_to_blob(&result, cell_indices, cells, num_partial_cells, &s);
+    ASSERT_EQUALS(ret, C_KZG_BADARGS);
+}
+
+static void test_cells_to_blob__fails_invalid_field_element(void) {
+    C_KZG_RET ret;
+    Blob blob, result;
+    uint64_t cell_indices[CELLS_PER_BLOB];
+    Cell cells[CELLS_PER_EXT_BLOB];
+
+    /* Get a random blob and its cells */
+    get_rand_blob(&blob);
+    ret = compute_cells_and_kzg_proofs(cells, NULL, &blob, &s);
+    ASSERT_EQUALS(ret, C_KZG_OK);
+
+    for (size_t i = 0; i < CELLS_PER_BLOB; i++) {
+        cell_indices[i] = i;
+    }
+
+    /* Make a field element in the last cell non-canonical */
+    memset(cells[CELLS_PER_BLOB - 1].bytes, 0xff, BYTES_PER_FIELD_ELEMENT);
+
+    ret = cells_to_blob(&result, cell_indices, cells, CELLS_PER_BLOB, &s);
+    ASSERT_EQUALS(ret, C_KZG_BADARGS);
+}
+
 static void test_compute_vanishing_polynomial_from_roots(void) {
     /*
      * Test case: (x - 2)(x - 3)
@@ -2072,6 +2448,55 @@ static void test_verify_cell_kzg_proof_batch__succeeds_random_blob(void) {
     ASSERT_EQUALS(ret, C_KZG_OK);
 }
 
+static void test_verify_cell_kzg_proof_batch__succeeds_with_g1_points(void) {
+    C_KZG_RET ret;
+    bool ok;
+    const size_t num_cells = 8;
+    Blob blobs[2];
+    KZGCommitment blob_commitments[2];
+    Cell blob_cells[2][CELLS_PER_EXT_BLOB];
+    KZGProof blob_proofs[2][CELLS_PER_EXT_BLOB];
+    Bytes48 commitments[num_cells], proofs[num_cells];
+    g1_t commitments_g1[num_cells], proofs_g1[num_cells];
+    uint64_t cell_indices[num_cells];
+    Cell cells[num_cells];
+
+    for (size_t i = 0; i < 2; i++) {
+        get_rand_blob(&blobs[i]);
+        ret = blob_to_kzg_commitment(&blob_commitments[i], &blobs[i], &s);
+        ASSERT_EQUALS(ret, C_KZG_OK);
+        ret = compute_cells_and_kzg_proofs(blob_cells[i], blob_proofs[i], &blobs[i], &s);
+        ASSERT_EQUALS(ret, C_KZG_OK);
+    }
+
+    /* Interleave cells from both blobs so commitments repeat out of order */
+    for (size_t i = 0; i < num_cells; i++) {
+        size_t blob_index = (i * 3 / 2) % 2;
+        cell_indices[i] = i * 5;
+        cells[i] = blob_cells[blob_index][cell_indices[i]];
+        commitments[i] = blob_commitments[blob_index];
+        proofs[i] = blob_proofs[blob_index][cell_indices[i]];
+        ret = bytes_to_kzg_commitment(&commitments_g1[i], &commitments[i]);
+        ASSERT_EQUALS(ret, C_KZG_OK);
+        ret = bytes_to_kzg_proof(&proofs_g1[i], &proofs[i]);
+        ASSERT_EQUALS(ret, C_KZG_OK);
+    }
+
+    ret = verify_cell_kzg_proof_batch_g1(
+        &ok, commitments, commitments_g1, cell_indices, cells, proofs, proofs_g1, num_cells, &s
+    );
+    ASSERT_EQUALS(ret, C_KZG_OK);
+    ASSERT_EQUALS(ok, true);
+
+    /* Changing a cell should make it fail */
+    cells[3] = blob_cells[0][0];
+    ret = verify_cell_kzg_proof_batch_g1(
+        &ok, commitments, commitments_g1, cell_indices, cells, proofs, proofs_g1, num_cells, &s
+    );
+    ASSERT_EQUALS(ret, C_KZG_OK);
+    ASSERT_EQUALS(ok, false);
+}
+
 ////////////////////////////////////////////////////////////////////////////////////////////////////
 // Profiling Functions
 ////////////////////////////////////////////////////////////////////////////////////////////////////
@@ -2302,6 +2727,9 @@ int main(void) {
     RUN(test_blob_to_kzg_commitment__fails_x_greater_than_modulus);
     RUN(test_blob_to_kzg_commitment__succeeds_point_at_infinity);
     RUN(test_blob_to_kzg_commitment__succeeds_expected_commitment);
+    RUN(test_accumulate_kzg_commitment__succeeds_matches_blob_commitment);
+    RUN(test_accumulate_kzg_commitment__fails_chunk_out_of_range);
+    RUN(test_accumulate_kzg_commitment__fails_x_equal_to_modulus);
     RUN(test_validate_kzg_g1__succeeds_round_trip);
     RUN(test_validate_kzg_g1__succeeds_correct_point);
     RUN(test_validate_kzg_g1__fails_not_in_g1);
@@ -2349,11 +2777,14 @@ int main(void) {
     RUN(test_compute_and_verify_blob_kzg_proof__fails_compute_commitment_not_in_g1);
     RUN(test_compute_and_verify_blob_kzg_proof__fails_verify_commitment_not_in_g1);
     RUN(test_compute_and_verify_blob_kzg_proof__fails_invalid_blob);
+    RUN(test_blob_to_kzg_commitment_and_proof__succeeds_matches_separate_calls);
+    RUN(test_blob_to_kzg_commitment_and_proof__fails_invalid_blob);
     RUN(test_verify_kzg_proof_batch__succeeds_round_trip);
     RUN(test_verify_kzg_proof_batch__fails_with_incorrect_proof);
     RUN(test_verify_kzg_proof_batch__fails_proof_not_in_g1);
     RUN(test_verify_kzg_proof_batch__fails_commitment_not_in_g1);
     RUN(test_verify_kzg_proof_batch__fails_invalid_blob);
+    RUN(test_verify_kzg_proof_batch__succeeds_with_g1_points);
     RUN(test_expand_root_of_unity__global_matches_expected);
     RUN(test_expand_root_of_unity__succeeds_with_root);
     RUN(test_expand_root_of_unity__fails_not_root_of_unity);
@@ -2365,11 +2796,20 @@ int main(void) {
     RUN(test_deduplicate_commitments__all_duplicates);
     RUN(test_deduplicate_commitments__no_commitments);
     RUN(test_deduplicate_commitments__one_commitment);
+    RUN(test_compute_cell_kzg_proofs__succeeds_few_cells);
+    RUN(test_compute_cell_kzg_proofs__succeeds_many_cells);
+    RUN(test_compute_cell_kzg_proofs__fails_invalid_cell_index);
     RUN(test_recover_cells_and_kzg_proofs__succeeds_random_blob);
+    RUN(test_recover_cells_and_kzg_proofs__succeeds_half_available);
     RUN(test_shift_factors__succeeds);
+    RUN(test_cells_to_blob__succeeds_first_half);
+    RUN(test_cells_to_blob__succeeds_needs_recovery);
+    RUN(test_cells_to_blob__fails_not_enough_cells);
+    RUN(test_cells_to_blob__fails_invalid_field_element);
     RUN(test_compute_vanishing_polynomial_from_roots);
     RUN(test_vanishing_polynomial_for_missing_cells);
     RUN(test_verify_cell_kzg_proof_batch__succeeds_random_blob);
+    RUN(test_verify_cell_kzg_proof_batch__succeeds_with_g1_points);
 
     /*
      * These functions are only executed if we're profiling. To me, it makes sense to put these in